@@ -67,6 +67,9 @@ mod macro_utils;
 #[cfg(feature = "item_parsing")]
 mod item_parsing;
 
+#[cfg(feature = "timed")]
+mod timed;
+
 
 #[cfg(feature = "macro_utils")]
 use crate::macro_utils_shared::Error;
@@ -81,6 +84,12 @@ pub fn macro_attr(attr: TokenStream, item: TokenStream) -> TokenStream {
     crate::macro_utils::macro_attr(attr, item).unwrap_or_else(Error::into_compile_error)
 }
 
+#[cfg(feature = "timed")]
+#[proc_macro_attribute]
+pub fn timed(attr: TokenStream, item: TokenStream) -> TokenStream {
+    crate::timed::timed(attr, item).unwrap_or_else(Error::into_compile_error)
+}
+
 
 #[doc(hidden)]
 #[proc_macro]
@@ -119,6 +128,63 @@ pub fn __priv_unwrap_bound(
 }
 
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __priv_dedup_lifetime_bounds(
+    input_tokens: proc_macro::TokenStream
+) -> proc_macro::TokenStream {
+    let input_tokens: TokenStream = input_tokens.into();
+
+    let mut iter = input_tokens.into_iter();
+
+    let bounds_tt = iter.next().expect("__priv_dedup_lifetime_bounds expected more tokens");
+    
+    let group = match &bounds_tt {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => group,
+        x => panic!("Expected a parenthesized group, found:\n{}", x)
+    };
+
+    // Flattening the none-delimited groups that lifetime fragments might be wrapped in
+    fn flatten(ts: TokenStream, out: &mut alloc::vec::Vec<TokenTree>) {
+        for tt in ts {
+            match tt {
+                TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                    flatten(group.stream(), out)
+                }
+                tt => out.push(tt),
+            }
+        }
+    }
+
+    let mut tts = alloc::vec::Vec::new();
+    flatten(group.stream(), &mut tts);
+
+    let mut seen = alloc::vec::Vec::<alloc::string::String>::new();
+    let mut bounds = TokenStream::new();
+
+    let mut tts = tts.into_iter();
+    while let Some(tick) = tts.next() {
+        // Lifetimes are a `'` followed by an identifier
+        let ident = tts.next().expect("expected the identifier of a lifetime");
+        let name = alloc::format!("{}{}", tick, ident);
+        if seen.contains(&name) {
+            continue;
+        }
+        seen.push(name);
+
+        bounds.extend(once(tick));
+        bounds.extend(once(ident));
+        bounds.extend(once(TokenTree::Punct(Punct::new('+', Spacing::Alone))));
+    }
+
+    let args = TokenStream::new();
+
+    parsing_shared::parse_path_and_args("__priv_dedup_lifetime_bounds", &mut iter, args, |args| {
+        args.extend(once(TokenTree::Group(Group::new(Delimiter::Parenthesis, bounds))));
+    }).into()
+}
+
+
 #[cfg(feature = "macro_utils")]
 #[doc(hidden)]
 #[proc_macro]
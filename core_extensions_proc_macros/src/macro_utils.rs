@@ -454,6 +454,27 @@ pub(crate) fn tokens_method(tokens: TokenStream) -> crate::Result<TokenStream> {
 
             args.extend(once(TokenTree::Group(outgroups.pop_front().unwrap())));
         }
+        "fold" => {
+            let init = parse_params(&mut iter)?;
+            let group = parse_bounded(&mut iter)?;
+
+            // Each element wraps the accumulator in another invocation of the callback,
+            // the innermost invocation gets the initial value.
+            let mut accum = init.stream();
+            for tt in group.stream() {
+                let mut call_args = args.clone();
+                out_parenthesized(accum, init.span(), &mut call_args);
+                out_parenthesized_tt(tt, &mut call_args);
+
+                let mut call_args = Group::new(macro_.delimiter, call_args);
+                call_args.set_span(macro_.delim_span);
+
+                accum = macro_.path_bang.clone();
+                accum.extend(once(TokenTree::Group(call_args)));
+            }
+
+            return Ok(accum);
+        }
     }
 
     Ok(macro_.into_token_stream())
@@ -22,6 +22,7 @@ const UNBOUNDED_ERR_CASES: &[(&str, &str)] = &[
     ("f!() zip_shortest: range(1..)", "Expected at least one finite list"),
     ("f!() zip_longest: range(1..)", "Expected at least one finite list"),
     ("f!() iterate: range(1..)", "Expected a bounded"),
+    ("f!() fold(a): range(1..)", "Expected a bounded"),
     ("f!() iterate: cycle((1)) ", "Expected a bounded"),
     ("f!() iterate: repeat(4, range(1..)) ", "Expected a bounded"),
     ("f!() iterate: skip(10, range(1..)) ", "Expected a bounded"),
@@ -0,0 +1,86 @@
+use crate::{
+    macro_utils_shared::{expect_no_tokens, parse_check_punct, parse_path_and_span, PathAndSpan},
+    used_proc_macro::{Delimiter, Group, TokenStream, TokenTree},
+    Error,
+    mmatches,
+};
+
+use core::iter::once;
+
+use alloc::{
+    format,
+    string::ToString,
+    vec::Vec,
+};
+
+
+#[cfg(test)]
+mod timed_tests;
+
+
+pub(crate) fn timed(attr: TokenStream, item: TokenStream) -> crate::Result<TokenStream> {
+    let crate_path = parse_crate_path(attr)?;
+
+    let mut item: Vec<TokenTree> = item.into_iter().collect();
+
+    let fn_index = item
+        .iter()
+        .position(|tt| mmatches!(tt, TokenTree::Ident(ident) if ident.to_string() == "fn"))
+        .ok_or_else(|| Error::end("expected a function"))?;
+
+    let name = match item.get(fn_index + 1) {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        Some(tt) => return Err(Error::one_tt(tt.span(), "expected the function name")),
+        None => return Err(Error::end("expected the function name")),
+    };
+
+    let body = match item.pop() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group,
+        Some(tt) => return Err(Error::one_tt(tt.span(), "expected the function body")),
+        None => return Err(Error::end("expected the function body")),
+    };
+
+    let mut new_body: TokenStream = "let __timed_guard = ".parse().unwrap();
+    new_body.extend(crate_path);
+    new_body.extend(
+        format!("::measure_time::__TimedGuard::new({:?});", name)
+            .parse::<TokenStream>()
+            .unwrap()
+    );
+    new_body.extend(once(TokenTree::Group(body.clone())));
+
+    let mut new_body = Group::new(Delimiter::Brace, new_body);
+    new_body.set_span(body.span());
+
+    let mut out: TokenStream = item.into_iter().collect();
+    out.extend(once(TokenTree::Group(new_body)));
+    Ok(out)
+}
+
+// Parses the optional `crate = path` argument,
+// defaulting to `::core_extensions` when there are no arguments.
+fn parse_crate_path(attr: TokenStream) -> crate::Result<TokenStream> {
+    let mut iter = attr.into_iter();
+
+    match iter.next() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "crate" => {}
+        Some(tt) => return Err(Error::one_tt(tt.span(), "expected `crate = path`")),
+        None => return Ok("::core_extensions".parse().unwrap()),
+    }
+
+    parse_check_punct(&mut iter, '=')?;
+
+    let PathAndSpan{path, start_span, terminator, ..} = parse_path_and_span(&mut iter)?;
+
+    if path.is_empty() {
+        return Err(Error::one_tt(start_span, "expected the path to core_extensions"));
+    }
+
+    match terminator {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => expect_no_tokens(iter)?,
+        Some(tt) => return Err(Error::one_tt(tt.span(), "expected a path")),
+        None => {}
+    }
+
+    Ok(path)
+}
@@ -0,0 +1,86 @@
+use crate::used_proc_macro::TokenStream;
+
+use crate::test_utils::remove_whitespaces;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+
+fn timed_str(attr: &str, item: &str) -> Result<String, String> {
+    let attr: TokenStream = attr.parse().unwrap();
+    let item: TokenStream = item.parse().unwrap();
+
+    super::timed(attr, item)
+        .map(|x| remove_whitespaces(&x.to_string()))
+        .map_err(|e| e.into_compile_error().to_string())
+}
+
+const GUARD: &str = "let __timed_guard = ::core_extensions::measure_time::__TimedGuard::new";
+
+#[test]
+fn timed_output() {
+    let cases: &[(&str, String)] = &[
+        (
+            "fn foo() {}",
+            format!("fn foo() {{ {}(\"foo\"); {{}} }}", GUARD),
+        ),
+        (
+            "#[inline] pub(crate) fn bar<T: Into<u64>>(x: T) -> u64 where T: Copy { x.into() }",
+            format!(
+                "#[inline] pub(crate) fn bar<T: Into<u64>>(x: T) -> u64 where T: Copy \
+                 {{ {}(\"bar\"); {{ x.into() }} }}",
+                GUARD,
+            ),
+        ),
+        (
+            "pub async unsafe fn baz(&self) -> Result<u32, ()> { let x = qux()?; Ok(x) }",
+            format!(
+                "pub async unsafe fn baz(&self) -> Result<u32, ()> \
+                 {{ {}(\"baz\"); {{ let x = qux()?; Ok(x) }} }}",
+                GUARD,
+            ),
+        ),
+    ];
+
+    for (item, expected) in cases {
+        assert_eq!(timed_str("", item).unwrap(), remove_whitespaces(expected));
+    }
+}
+
+#[test]
+fn timed_crate_path() {
+    let cases: &[(&str, &str)] = &[
+        ("crate = ::core_extensions", "::core_extensions"),
+        ("crate = ce", "ce"),
+        ("crate = ::foo::bar,", "::foo::bar"),
+        ("crate = crate::reexports::ce", "crate::reexports::ce"),
+    ];
+
+    for (attr, path) in cases {
+        let expected = format!(
+            "fn foo() {{ let __timed_guard = {}::measure_time::__TimedGuard::new(\"foo\"); {{}} }}",
+            path,
+        );
+        assert_eq!(timed_str(attr, "fn foo() {}").unwrap(), remove_whitespaces(&expected));
+    }
+}
+
+#[test]
+fn timed_errors() {
+    let cases: &[(&str, &str, &str)] = &[
+        ("foo", "fn foo() {}", "expected `crate = path`"),
+        ("crate", "fn foo() {}", "expected '='"),
+        ("crate =", "fn foo() {}", "expected the path to core_extensions"),
+        ("crate = foo, bar", "fn foo() {}", "expected no more tokens"),
+        ("", "struct Foo {}", "expected a function"),
+        ("", "fn", "expected the function name"),
+        ("", "fn foo();", "expected the function body"),
+    ];
+
+    for (attr, item, expected) in cases {
+        let err = timed_str(attr, item).unwrap_err();
+        assert!(err.contains(expected), "{}", err);
+    }
+}
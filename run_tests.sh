@@ -7,6 +7,7 @@ cd core_extensions_proc_macros
 
 cargo test --features item_parsing
 cargo test --features item_parsing derive
+cargo test --features timed
 
 cd ..
 
@@ -29,6 +30,7 @@ cargo test --no-default-features --features "rust_1_46 std phantom"
 cargo test --no-default-features --features "rust_1_46 std self_ops"
 cargo test --no-default-features --features "rust_1_46 std slices"
 cargo test --no-default-features --features "rust_1_46 std strings"
+cargo test --no-default-features --features "rust_1_46 std timed"
 cargo test --no-default-features --features "rust_1_46 std transparent_newtype"
 cargo test --no-default-features --features "rust_1_46 std transparent_newtype derive"
 cargo test --no-default-features --features "rust_1_46 std type_asserts"
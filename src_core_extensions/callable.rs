@@ -9,6 +9,16 @@
 
 
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(feature = "std")]
+use std_::{
+    collections::HashMap,
+    fmt::{self, Debug},
+    hash::Hash,
+};
+
 #[cfg(test)]
 mod tests;
 
@@ -159,11 +169,690 @@ pub trait CallExt {
     {
         self.into_call_(params)
     }
+
+    /// Calls this function once for each element of `args`,
+    /// collecting the return values into a `Vec`.
+    ///
+    /// Every element is cloned to pass it by value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core_extensions::{impl_call, CallExt};
+    ///
+    /// struct MulBy(u32);
+    ///
+    /// impl_call! { 
+    ///     fn ref_call(self: MulBy, n: u32) -> u32 {
+    ///         self.0 * n
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(MulBy(2).apply_slice(&[1, 2, 3]), vec![2, 4, 6]);
+    /// assert_eq!(MulBy(10).apply_slice(&[]), Vec::<u32>::new());
+    ///
+    /// // Closures take their parameters as tuples
+    /// let words = [("foo".to_string(),), ("bar".to_string(),)];
+    /// assert_eq!((|s: String| s + "!").apply_slice(&words), vec!["foo!", "bar!"]);
+    ///
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn apply_slice<P>(&self, args: &[P]) -> Vec<Self::Returns>
+    where
+        Self: CallRef<P>,
+        P: Clone,
+    {
+        args.iter().map(|arg| self.ref_call_(arg.clone())).collect()
+    }
+
+    /// Fixes the first parameter of this function,
+    /// returning a [`Partial`] which takes the remaining parameter.
+    ///
+    /// Calling the returned [`Partial`] with `second` calls `self` with `(first, second)`.
+    /// 
+    /// The `CallRef`/`CallMut` impls of [`Partial`] clone `first` on each call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core_extensions::{impl_call, CallExt};
+    ///
+    /// struct Pow;
+    ///
+    /// impl_call! { 
+    ///     fn ref_call(self: Pow, base: u32, exponent: u32) -> u32 {
+    ///         base.pow(exponent)
+    ///     }
+    /// }
+    ///
+    /// let pow2 = Pow.partial(2);
+    /// assert_eq!(pow2.ref_call(3), 8);
+    /// assert_eq!(pow2.ref_call(10), 1024);
+    ///
+    /// let mut list = Vec::new();
+    /// let mut push_pair = (|x: char, y: u32| list.push((x, y))).partial('a');
+    /// push_pair.mut_call(3);
+    /// push_pair.mut_call(5);
+    /// assert_eq!(list, [('a', 3), ('a', 5)]);
+    /// 
+    /// let concat = (|x: String, y: &str| x + y).partial("foo".to_string());
+    /// assert_eq!(concat.into_call("bar"), "foobar");
+    ///
+    /// ```
+    /// 
+    /// [`Partial`]: ./callable/struct.Partial.html
+    #[inline(always)]
+    fn partial<P>(self, first: P) -> Partial<Self, P>
+    where
+        Self: Sized,
+    {
+        Partial::new(self, first)
+    }
+
+    /// Composes this function with `g`,
+    /// returning a [`Compose`] which passes the value returned by `self` to `g`.
+    ///
+    /// [`Compose`] implements the same `Call*` traits that both `self` and `g` implement.
+    ///
+    /// Because closures take their parameters as tuples,
+    /// a closure can only be passed as `g` if `self` returns a tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core_extensions::{impl_call, CallExt};
+    ///
+    /// struct AddOne;
+    ///
+    /// impl_call! { 
+    ///     fn ref_call(self: AddOne, n: u32) -> u32 {
+    ///         n + 1
+    ///     }
+    /// }
+    ///
+    /// struct Double;
+    ///
+    /// impl_call! { 
+    ///     fn ref_call(self: Double, n: u32) -> u32 {
+    ///         n * 2
+    ///     }
+    /// }
+    ///
+    /// let add_then_double = AddOne.then(Double);
+    /// assert_eq!(add_then_double.ref_call(3), 8);
+    /// assert_eq!(add_then_double.into_call(10), 22);
+    ///
+    /// assert_eq!(Double.then(AddOne).into_call(3), 7);
+    ///
+    /// let mut total = 0;
+    /// let mut add_to_total = (|n: u32| { total += n; total }).then(Double);
+    /// assert_eq!(add_to_total.mut_call((3,)), 6);
+    /// assert_eq!(add_to_total.mut_call((5,)), 16);
+    ///
+    /// let len_times_ten = (|s: &str| (s.len(),)).then(|len: usize| len * 10);
+    /// assert_eq!(len_times_ten.ref_call(("hello",)), 50);
+    ///
+    /// ```
+    /// 
+    /// [`Compose`]: ./callable/struct.Compose.html
+    #[inline(always)]
+    fn then<G>(self, g: G) -> Compose<Self, G>
+    where
+        Self: Sized,
+    {
+        Compose::new(self, g)
+    }
+
+    /// Wraps this function in a [`Memoized`],
+    /// which caches the value returned for each distinct parameter.
+    ///
+    /// The wrapped function is only called the first time that
+    /// the [`Memoized`] is called with a parameter,
+    /// later calls with an equal parameter return a clone of the cached value.
+    ///
+    /// [`Memoized`] only implements `CallMut` and `CallInto`,
+    /// because the cache must be mutated when it's called.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core_extensions::CallExt;
+    ///
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    ///
+    /// let mut square = (|x: u64| {
+    ///     calls.set(calls.get() + 1);
+    ///     x * x
+    /// }).memoized();
+    ///
+    /// // Closures take their parameters as tuples
+    /// assert_eq!(square.mut_call((3,)), 9);
+    /// assert_eq!(square.mut_call((3,)), 9);
+    /// assert_eq!(calls.get(), 1);
+    ///
+    /// assert_eq!(square.mut_call((5,)), 25);
+    /// assert_eq!(square.mut_call((3,)), 9);
+    /// assert_eq!(square.mut_call((5,)), 25);
+    /// assert_eq!(calls.get(), 2);
+    ///
+    /// ```
+    /// 
+    /// [`Memoized`]: ./callable/struct.Memoized.html
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+    #[inline(always)]
+    fn memoized<P>(self) -> Memoized<Self, P>
+    where
+        Self: Sized + CallInto<P>,
+        P: Hash + Eq,
+    {
+        Memoized::new(self)
+    }
 }
 
 impl<T: ?Sized> CallExt for T {}
 
 
+/// A function with its first parameter fixed,
+/// which takes the remaining parameter when called.
+///
+/// Calling a `Partial` with `second` calls the wrapped function with `(first, second)`.
+///
+/// This is constructed with the [`CallExt::partial`] method,
+/// which has examples.
+///
+/// [`CallExt::partial`]: ../trait.CallExt.html#method.partial
+#[derive(Debug, Copy, Clone)]
+pub struct Partial<F, P> {
+    func: F,
+    first: P,
+}
+
+impl<F, P> Partial<F, P> {
+    /// Constructs a `Partial`.
+    pub fn new(func: F, first: P) -> Self {
+        Self { func, first }
+    }
+
+    /// Unwraps this into the function and the fixed first parameter.
+    pub fn into_inner(self) -> (F, P) {
+        (self.func, self.first)
+    }
+}
+
+impl<F, P, Q> CallRef<Q> for Partial<F, P>
+where
+    F: CallRef<(P, Q)>,
+    P: Clone,
+{
+    #[inline]
+    fn ref_call_(&self, second: Q) -> F::Returns {
+        self.func.ref_call_((self.first.clone(), second))
+    }
+}
+
+impl<F, P, Q> CallMut<Q> for Partial<F, P>
+where
+    F: CallMut<(P, Q)>,
+    P: Clone,
+{
+    #[inline]
+    fn mut_call_(&mut self, second: Q) -> F::Returns {
+        self.func.mut_call_((self.first.clone(), second))
+    }
+}
+
+impl<F, P, Q> CallInto<Q> for Partial<F, P>
+where
+    F: CallInto<(P, Q)>,
+{
+    type Returns = F::Returns;
+
+    #[inline]
+    fn into_call_(self, second: Q) -> F::Returns {
+        self.func.into_call_((self.first, second))
+    }
+}
+
+
+/// Two composed functions,
+/// which passes the value returned by the first function to the second one.
+///
+/// This implements the `Call*` traits that both functions implement.
+///
+/// This is constructed with the [`CallExt::then`] method,
+/// which has examples.
+///
+/// [`CallExt::then`]: ../trait.CallExt.html#method.then
+#[derive(Debug, Copy, Clone)]
+pub struct Compose<F, G> {
+    first: F,
+    second: G,
+}
+
+impl<F, G> Compose<F, G> {
+    /// Constructs a `Compose`.
+    pub fn new(first: F, second: G) -> Self {
+        Self { first, second }
+    }
+
+    /// Unwraps this into the first and second functions.
+    pub fn into_inner(self) -> (F, G) {
+        (self.first, self.second)
+    }
+}
+
+impl<F, G, P> CallRef<P> for Compose<F, G>
+where
+    F: CallRef<P>,
+    G: CallRef<F::Returns>,
+{
+    #[inline]
+    fn ref_call_(&self, params: P) -> G::Returns {
+        self.second.ref_call_(self.first.ref_call_(params))
+    }
+}
+
+impl<F, G, P> CallMut<P> for Compose<F, G>
+where
+    F: CallMut<P>,
+    G: CallMut<F::Returns>,
+{
+    #[inline]
+    fn mut_call_(&mut self, params: P) -> G::Returns {
+        self.second.mut_call_(self.first.mut_call_(params))
+    }
+}
+
+impl<F, G, P> CallInto<P> for Compose<F, G>
+where
+    F: CallInto<P>,
+    G: CallInto<F::Returns>,
+{
+    type Returns = G::Returns;
+
+    #[inline]
+    fn into_call_(self, params: P) -> G::Returns {
+        self.second.into_call_(self.first.into_call_(params))
+    }
+}
+
+
+/// A function which caches the value it returns for each distinct parameter.
+///
+/// The wrapped function is only called once for each distinct parameter,
+/// later calls return a clone of the cached value.
+///
+/// This only implements `CallMut` and `CallInto`, not `CallRef`,
+/// because the cache is mutated when it's called.
+///
+/// This is constructed with the [`CallExt::memoized`] method,
+/// which has examples.
+///
+/// [`CallExt::memoized`]: ../trait.CallExt.html#method.memoized
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+pub struct Memoized<F, P>
+where
+    F: CallInto<P>,
+{
+    func: F,
+    cache: HashMap<P, F::Returns>,
+}
+
+#[cfg(feature = "std")]
+impl<F, P> Memoized<F, P>
+where
+    F: CallInto<P>,
+    P: Hash + Eq,
+{
+    /// Constructs a `Memoized` with an empty cache.
+    pub fn new(func: F) -> Self {
+        Self {
+            func,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Unwraps this into the wrapped function, discarding the cache.
+    pub fn into_inner(self) -> F {
+        self.func
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, P> Clone for Memoized<F, P>
+where
+    F: CallInto<P> + Clone,
+    P: Clone,
+    F::Returns: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            func: self.func.clone(),
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, P> Debug for Memoized<F, P>
+where
+    F: CallInto<P> + Debug,
+    P: Debug,
+    F::Returns: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Memoized")
+            .field("func", &self.func)
+            .field("cache", &self.cache)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, P> CallMut<P> for Memoized<F, P>
+where
+    F: CallMut<P>,
+    P: Hash + Eq + Clone,
+    F::Returns: Clone,
+{
+    fn mut_call_(&mut self, params: P) -> F::Returns {
+        if let Some(ret) = self.cache.get(&params) {
+            return ret.clone();
+        }
+        let ret = self.func.mut_call_(params.clone());
+        self.cache.insert(params, ret.clone());
+        ret
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, P> CallInto<P> for Memoized<F, P>
+where
+    F: CallInto<P>,
+    P: Hash + Eq,
+{
+    type Returns = F::Returns;
+
+    fn into_call_(mut self, params: P) -> F::Returns {
+        match self.cache.remove(&params) {
+            Some(ret) => ret,
+            None => self.func.into_call_(params),
+        }
+    }
+}
+
+
+/// Calls the function if it's `Some`, returning `None` if it's `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::CallExt;
+///
+/// let mut callback = Some(|x: u32| x * 2);
+/// assert_eq!(callback.ref_call((3,)), Some(6));
+/// assert_eq!(callback.mut_call((5,)), Some(10));
+///
+/// callback = None;
+/// assert_eq!(callback.ref_call((3,)), None);
+/// assert_eq!(callback.into_call((5,)), None);
+///
+/// ```
+impl<F, P> CallRef<P> for Option<F>
+where
+    F: CallRef<P>,
+{
+    #[inline]
+    fn ref_call_(&self, params: P) -> Option<F::Returns> {
+        self.as_ref().map(|func| func.ref_call_(params))
+    }
+}
+
+impl<F, P> CallMut<P> for Option<F>
+where
+    F: CallMut<P>,
+{
+    #[inline]
+    fn mut_call_(&mut self, params: P) -> Option<F::Returns> {
+        self.as_mut().map(|func| func.mut_call_(params))
+    }
+}
+
+impl<F, P> CallInto<P> for Option<F>
+where
+    F: CallInto<P>,
+{
+    type Returns = Option<F::Returns>;
+
+    #[inline]
+    fn into_call_(self, params: P) -> Option<F::Returns> {
+        self.map(|func| func.into_call_(params))
+    }
+}
+
+
+/// A boxed [`CallRef`] trait object, taking `P` as the parameter and returning `R`.
+///
+/// This implements [`CallRef`], [`CallMut`], and [`CallInto`].
+///
+/// This can be constructed with [`box_call_ref`], or by coercing a `Box<F>`.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::callable::{BoxCallRef, box_call_ref};
+/// use core_extensions::CallExt;
+///
+/// let funcs: Vec<BoxCallRef<(u32,), u32>> = vec![
+///     box_call_ref(|x: u32| x + 1),
+///     box_call_ref(|x: u32| x * 2),
+///     Box::new(|x: u32| x.pow(2)),
+/// ];
+///
+/// let results = funcs.iter().map(|f| f.ref_call((5,))).collect::<Vec<u32>>();
+/// assert_eq!(results, [6, 10, 25]);
+///
+/// ```
+///
+/// [`box_call_ref`]: ./fn.box_call_ref.html
+/// [`CallRef`]: ../trait.CallRef.html
+/// [`CallMut`]: ../trait.CallMut.html
+/// [`CallInto`]: ../trait.CallInto.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+pub type BoxCallRef<'a, P, R> = Box<dyn CallRef<P, Returns = R> + 'a>;
+
+/// A boxed [`CallMut`] trait object, taking `P` as the parameter and returning `R`.
+///
+/// This implements [`CallMut`] and [`CallInto`].
+///
+/// This can be constructed with [`box_call_mut`], or by coercing a `Box<F>`.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::callable::{BoxCallMut, box_call_mut};
+/// use core_extensions::CallExt;
+///
+/// let mut total = 0;
+/// let mut count = 0;
+/// {
+///     let mut funcs: Vec<BoxCallMut<(u32,), ()>> = vec![
+///         box_call_mut(|x: u32| total += x),
+///         box_call_mut(|_: u32| count += 1),
+///     ];
+///     
+///     for x in [3, 5, 8].iter() {
+///         for func in &mut funcs {
+///             func.mut_call((*x,));
+///         }
+///     }
+/// }
+/// assert_eq!(total, 16);
+/// assert_eq!(count, 3);
+///
+/// ```
+///
+/// [`box_call_mut`]: ./fn.box_call_mut.html
+/// [`CallMut`]: ../trait.CallMut.html
+/// [`CallInto`]: ../trait.CallInto.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+pub type BoxCallMut<'a, P, R> = Box<dyn CallMut<P, Returns = R> + 'a>;
+
+/// A boxed [`CallIntoBox`] trait object, taking `P` as the parameter and returning `R`.
+///
+/// This implements [`CallInto`],
+/// it's a `CallIntoBox` trait object because `CallInto` trait objects can't be called.
+///
+/// This can be constructed with [`box_call_into`], or by coercing a `Box<F>`.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::callable::{BoxCallInto, box_call_into};
+/// use core_extensions::CallExt;
+///
+/// let list = vec![3, 5, 8];
+/// let func: BoxCallInto<(usize,), Vec<u32>> = box_call_into(move |n: usize| {
+///     list.into_iter().take(n).collect()
+/// });
+///
+/// assert_eq!(func.into_call((2,)), [3, 5]);
+///
+/// ```
+///
+/// [`box_call_into`]: ./fn.box_call_into.html
+/// [`CallIntoBox`]: ./trait.CallIntoBox.html
+/// [`CallInto`]: ../trait.CallInto.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+pub type BoxCallInto<'a, P, R> = Box<dyn CallIntoBox<P, Returns = R> + 'a>;
+
+/// For calling boxed [`CallInto`] implementors, used by [`BoxCallInto`].
+///
+/// This is implemented for all types that implement [`CallInto`].
+///
+/// [`CallInto`]: ../trait.CallInto.html
+/// [`BoxCallInto`]: ./type.BoxCallInto.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+pub trait CallIntoBox<Params>: CallInto<Params> {
+    /// Calls this boxed function.
+    fn into_call_box_(self: Box<Self>, params: Params) -> Self::Returns;
+}
+
+#[cfg(feature = "alloc")]
+impl<F, P> CallIntoBox<P> for F
+where
+    F: CallInto<P>,
+{
+    #[inline]
+    fn into_call_box_(self: Box<Self>, params: P) -> F::Returns {
+        (*self).into_call_(params)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, P, R> CallRef<P> for BoxCallRef<'a, P, R> {
+    #[inline]
+    fn ref_call_(&self, params: P) -> R {
+        (**self).ref_call_(params)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, P, R> CallMut<P> for BoxCallRef<'a, P, R> {
+    #[inline]
+    fn mut_call_(&mut self, params: P) -> R {
+        (**self).ref_call_(params)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, P, R> CallInto<P> for BoxCallRef<'a, P, R> {
+    type Returns = R;
+
+    #[inline]
+    fn into_call_(self, params: P) -> R {
+        (*self).ref_call_(params)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, P, R> CallMut<P> for BoxCallMut<'a, P, R> {
+    #[inline]
+    fn mut_call_(&mut self, params: P) -> R {
+        (**self).mut_call_(params)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, P, R> CallInto<P> for BoxCallMut<'a, P, R> {
+    type Returns = R;
+
+    #[inline]
+    fn into_call_(mut self, params: P) -> R {
+        (*self).mut_call_(params)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, P, R> CallInto<P> for BoxCallInto<'a, P, R> {
+    type Returns = R;
+
+    #[inline]
+    fn into_call_(self, params: P) -> R {
+        self.into_call_box_(params)
+    }
+}
+
+/// Boxes `func` into a [`BoxCallRef`] trait object.
+///
+/// [`BoxCallRef`]: ./type.BoxCallRef.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn box_call_ref<'a, F, P>(func: F) -> BoxCallRef<'a, P, F::Returns>
+where
+    F: CallRef<P> + 'a,
+{
+    Box::new(func)
+}
+
+/// Boxes `func` into a [`BoxCallMut`] trait object.
+///
+/// [`BoxCallMut`]: ./type.BoxCallMut.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn box_call_mut<'a, F, P>(func: F) -> BoxCallMut<'a, P, F::Returns>
+where
+    F: CallMut<P> + 'a,
+{
+    Box::new(func)
+}
+
+/// Boxes `func` into a [`BoxCallInto`] trait object.
+///
+/// [`BoxCallInto`]: ./type.BoxCallInto.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn box_call_into<'a, F, P>(func: F) -> BoxCallInto<'a, P, F::Returns>
+where
+    F: CallInto<P> + 'a,
+{
+    Box::new(func)
+}
+
+
+
 /// Implementable alternative to [`std::ops::Fn`].
 ///
 /// # Parameters
@@ -532,6 +1221,29 @@ let env = Environment("hello");
 assert_eq!(env.into_call(()), "hello");
 ```
 
+### Implementing all the `Call*` traits.
+
+`all_calls` implements the same traits as `ref_call`,
+it's meant to make it explicit that `CallMut` and `CallInto` are implemented
+by delegating to `CallRef`.
+
+```rust
+use core_extensions::{impl_call, CallExt};
+
+struct Adder(u32);
+
+impl_call!{
+    fn all_calls(self: Adder, l: u32, r: u32) -> u32 {
+        self.0 + l + r
+    }
+}
+
+let mut adder = Adder(100);
+assert_eq!(adder.ref_call((3, 5)), 108);
+assert_eq!(adder.mut_call((8, 13)), 121);
+assert_eq!(adder.into_call((21, 34)), 155);
+```
+
 
 
 
@@ -551,8 +1263,11 @@ assert_eq!(env.into_call(()), "hello");
 
 $(#[$meta:meta])*
 
-// <fn_method_name> is one of (into_call|mut_call|ref_call),determining which trait
-// is implemented.
+// <fn_method_name> is one of (into_call|mut_call|ref_call|all_calls),
+// determining which traits are implemented:
+// - into_call: CallInto
+// - mut_call: CallMut and CallInto
+// - ref_call/all_calls: CallRef, CallMut, and CallInto
 fn <fn_method_name>
 
 // Optionally declares the generic parameters of the function.
@@ -711,6 +1426,18 @@ macro_rules! __priv_impl_call {
         }
     };
 
+    (inner_fn;
+        $(#[$meta:meta])*
+        fn all_calls
+        $($rem:tt)*
+    )=>{
+        $crate::__priv_impl_call!{
+            inner_fn;
+            $(#[$meta])*
+            fn ref_call
+            $($rem)*
+        }
+    };
     (inner_fn;
         $(#[$meta:meta])*
         fn ref_call
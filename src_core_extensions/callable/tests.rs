@@ -67,6 +67,27 @@ fn test_into_call() {
     assert_eq!(WhatInto(1u8).into_call(PhantomData::<u16>), 1);
 }
 
+#[test]
+fn test_all_calls() {
+    struct WhatAll {
+        offset: u32,
+    }
+
+    impl_call! {
+        fn all_calls(self: WhatAll, a: u32, b: u32) -> u32 {
+            self.offset + a * b
+        }
+    }
+
+    let mut env = WhatAll { offset: 100 };
+    assert_eq!(env.ref_call((2, 3)), 106);
+    assert_eq!(CallRef::ref_call_(&env, (2, 5)), 110);
+    assert_eq!(env.mut_call((3, 5)), 115);
+    assert_eq!(CallMut::mut_call_(&mut env, (3, 7)), 121);
+    assert_eq!(CallInto::into_call_(WhatAll { offset: 0 }, (5, 8)), 40);
+    assert_eq!(env.into_call((5, 7)), 135);
+}
+
 #[test]
 fn parameter_counts() {
     struct ZeroParam;
@@ -222,3 +243,171 @@ fn test_closures() {
 
     assert_eq!(into_fn.into_call(()), [0, 1, 2]);
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn test_memoized() {
+    struct CountCalls {
+        calls: u32,
+    }
+
+    impl_call! {
+        fn mut_call(self: CountCalls, l: u32, r: u32) -> u32 {
+            self.calls += 1;
+            l * r
+        }
+    }
+
+    let mut memo = CountCalls { calls: 0 }.memoized();
+    assert_eq!(memo.mut_call((3, 5)), 15);
+    assert_eq!(memo.mut_call((3, 5)), 15);
+    assert_eq!(memo.mut_call((5, 3)), 15);
+    assert_eq!(memo.mut_call((2, 8)), 16);
+    assert_eq!(memo.mut_call((5, 3)), 15);
+    assert_eq!(memo.mut_call((2, 8)), 16);
+    assert_eq!(memo.into_inner().calls, 3);
+
+    let mut memo = CountCalls { calls: 0 }.memoized();
+    assert_eq!(memo.mut_call((2, 8)), 16);
+    assert_eq!(memo.into_call((2, 8)), 16);
+    assert_eq!(CountCalls { calls: 0 }.memoized().into_call((3, 8)), 24);
+}
+
+#[test]
+fn test_option_calls() {
+    let mut n = 0;
+    let mut counter = Some(|x: u32| {
+        n += x;
+        n
+    });
+    assert_eq!(counter.mut_call((3,)), Some(3));
+    assert_eq!(counter.mut_call((5,)), Some(8));
+    assert_eq!(counter.into_call((8,)), Some(16));
+
+    let mut counter = None::<fn(u32) -> u32>;
+    assert_eq!(counter.ref_call((3,)), None);
+    assert_eq!(counter.mut_call((3,)), None);
+    assert_eq!(counter.into_call((3,)), None);
+
+    struct AddOne;
+
+    impl_call! {
+        fn ref_call(self: AddOne, x: u32) -> u32 {
+            x + 1
+        }
+    }
+
+    assert_eq!(Some(AddOne).ref_call(3), Some(4));
+    assert_eq!(None::<AddOne>.ref_call(3), None);
+    assert_eq!(Some(Some(AddOne)).ref_call(3), Some(Some(4)));
+    assert_eq!(Some(None::<AddOne>).ref_call(3), Some(None));
+}
+
+#[test]
+fn test_compose() {
+    struct AddOne;
+
+    impl_call! {
+        fn ref_call(self: AddOne, x: u32) -> u32 {
+            x + 1
+        }
+    }
+
+    struct Double;
+
+    impl_call! {
+        fn ref_call(self: Double, x: u32) -> u32 {
+            x * 2
+        }
+    }
+
+    assert_eq!(AddOne.then(Double).into_call(3), 8);
+    assert_eq!(AddOne.then(Double).ref_call(3), 8);
+    assert_eq!(AddOne.then(Double).mut_call(3), 8);
+    assert_eq!(Double.then(AddOne).into_call(3), 7);
+    assert_eq!(AddOne.then(Double).then(AddOne).ref_call(5), 13);
+
+    let mut calls = [0; 2];
+    {
+        let mut i = 0;
+        let mut logged = (|x: u32| {
+            calls[i] = x;
+            i += 1;
+            x
+        }).then(Double);
+        assert_eq!(logged.mut_call((3,)), 6);
+        assert_eq!(logged.mut_call((8,)), 16);
+    }
+    assert_eq!(calls, [3, 8]);
+
+    // Closures passed as the second function must take the returned tuple
+    let tupled = (|x: u32| (x, x + 1)).then(|x: u32, y: u32| x * y);
+    assert_eq!(tupled.ref_call((3,)), 12);
+
+    #[cfg(feature = "alloc")]
+    {
+        let owned = "foo".to_string();
+        let concat = (move |x: &str| (owned + x,)).then(|s: String| s.len());
+        assert_eq!(concat.into_call(("bar",)), 6);
+    }
+
+    let (first, second) = AddOne.then(Double).into_inner();
+    assert_eq!((first.ref_call(1), second.ref_call(1)), (2, 2));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_boxed_calls() {
+    use super::{
+        box_call_into, box_call_mut, box_call_ref, BoxCallInto, BoxCallMut, BoxCallRef,
+    };
+    use alloc::{boxed::Box, vec::Vec};
+
+    struct AddOne;
+
+    impl_call! {
+        fn ref_call(self: AddOne, x: u32) -> u32 {
+            x + 1
+        }
+    }
+
+    let strategies: Vec<BoxCallRef<(u32,), u32>> = vec![
+        box_call_ref(|x: u32| x + 1),
+        Box::new(|x: u32| x * 2),
+    ];
+    let mapped = strategies.iter().map(|f| f.ref_call((5,))).collect::<Vec<u32>>();
+    assert_eq!(mapped, [6, 10]);
+
+    let mut boxed_ref = box_call_ref(AddOne);
+    assert_eq!(boxed_ref.ref_call(3), 4);
+    assert_eq!(boxed_ref.mut_call(3), 4);
+    assert_eq!(boxed_ref.into_call(5), 6);
+
+    let mut n = 0;
+    {
+        let mut strategies: Vec<BoxCallMut<(u32,), u32>> = vec![
+            Box::new(|x: u32| x + 1),
+            box_call_mut(|x: u32| {
+                n += x;
+                n
+            }),
+        ];
+        for func in &mut strategies {
+            func.mut_call((3,));
+        }
+        assert_eq!(strategies[1].mut_call((5,)), 8);
+        assert_eq!(strategies.pop().unwrap().into_call((8,)), 16);
+    }
+    assert_eq!(n, 16);
+
+    let owned = "foo".to_string();
+    let boxed_into: BoxCallInto<(&str,), String> = box_call_into(move |x: &str| owned + x);
+    assert_eq!(boxed_into.into_call(("bar",)), "foobar");
+
+    let owned = "baz".to_string();
+    let boxed_into: BoxCallInto<(&str,), String> = Box::new(move |x: &str| owned + x);
+    assert_eq!(boxed_into.into_call(("qux",)), "bazqux");
+
+    let boxed_into = box_call_into(AddOne);
+    assert_eq!(boxed_into.into_call(8), 9);
+}
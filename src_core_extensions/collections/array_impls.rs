@@ -1,5 +1,6 @@
 use super::{
     Cloned,
+    FromArray,
     IntoArray,
 };
 
@@ -17,9 +18,9 @@ macro_rules! array_impls {
             const V: MaybeUninit<T> = MaybeUninit::uninit();
         }
 
-        /// When the "const_params" feature is disabled,
+        /// When the "rust_1_51" feature is disabled,
         /// the Cloned trait is implemented for arrays up to 32 elements long.
-        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_params")))]
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
         impl<'a, T, const N: usize> Cloned for [T; N]
         where
             T: Cloned
@@ -71,6 +72,36 @@ macro_rules! array_impls {
                 self
             }
         }
+
+        /// When the "rust_1_51" feature is disabled,
+        /// the FromArray trait is implemented for arrays up to 32 elements long.
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+        impl<T, const N: usize> FromArray for [T; N] {
+            type Array = Self;
+
+            fn from_array(array: Self)->Self {
+                array
+            }
+        }
+
+        /// When the "rust_1_51" feature is disabled,
+        /// this is only implemented for arrays up to 32 elements long.
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+        impl<'a, T, const N: usize> IntoArray for &'a [T; N] {
+            type Array = [&'a T; N];
+
+            fn into_array(self) -> [&'a T; N] {
+                let mut out = [MaybeUninit::<&'a T>::uninit(); N];
+                for (out, elem) in out.iter_mut().zip(self.iter()) {
+                    *out = MaybeUninit::new(elem);
+                }
+
+                // Safety: every element of `out` was initialized in the loop above.
+                unsafe{
+                    ::utils::transmute_ignore_size::<[MaybeUninit<&'a T>; N], [&'a T; N]>(out)
+                }
+            }
+        }
     }
 }
 
@@ -106,6 +137,24 @@ macro_rules! array_impls {
                     self
                 }
             }
+
+            impl<T> FromArray for [T;$size] {
+                type Array=Self;
+
+                fn from_array(array: Self)->Self {
+                    array
+                }
+            }
+
+            impl<'a,T> IntoArray for &'a [T;$size] {
+                type Array=[&'a T;$size];
+
+                fn into_array(self)->[&'a T;$size] {
+                    [
+                        $(&self[$elem],)*
+                    ]
+                }
+            }
         )*
     )
 }
@@ -237,6 +286,33 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "rust_1_51")]
+    fn cloned_longer_than_32() {
+        let mut values = [0u32; 40];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = i as u32 * 3;
+        }
+
+        let mut refs = [&0u32; 40];
+        for (ref_, value) in refs.iter_mut().zip(values.iter()) {
+            *ref_ = value;
+        }
+
+        let cloned: [u32; 40] = refs.cloned_();
+        assert_eq!(cloned, values);
+
+        let mut nested = [(&0u32, Some(&0u32)); 40];
+        for (pair, value) in nested.iter_mut().zip(values.iter()) {
+            *pair = (value, Some(value));
+        }
+
+        let cloned: [(u32, Option<u32>); 40] = nested.cloned_();
+        for (i, pair) in cloned.iter().enumerate() {
+            assert_eq!(*pair, (values[i], Some(values[i])));
+        }
+    }
+
     #[test]
     fn cloned_dest() {
         use std_::cell::Cell;
@@ -352,7 +428,41 @@ mod tests {
         #[cfg(feature = "rust_1_51")]
         into_array_tests! {
             [0;33],
+            [0;40],
             [0;65],
         }
     }
+
+    #[test]
+    fn from_array() {
+        assert_eq!(<[u8; 0]>::from_array([]), []);
+        assert_eq!(<[u8; 3]>::from_array([3, 5, 8]), [3, 5, 8]);
+        assert_eq!(<[u8; 32]>::from_array([1; 32]), [1; 32]);
+
+        #[cfg(feature = "rust_1_51")]
+        assert_eq!(<[u8; 65]>::from_array([1; 65])[..], [1; 65][..]);
+    }
+
+    #[test]
+    fn ref_into_array() {
+        let array = [3, 5, 8];
+        let refs: [&u32; 3] = (&array).into_array();
+        assert_eq!(refs, [&3, &5, &8]);
+        assert!(std_::ptr::eq(refs[1], &array[1]));
+
+        assert_eq!((&[0u8; 0]).into_array(), [&0u8; 0]);
+        assert_eq!((&[0u8; 32]).into_array(), [&0u8; 32]);
+
+        #[cfg(feature = "rust_1_51")]
+        {
+            let mut array = [0u32; 40];
+            for (i, elem) in array.iter_mut().enumerate() {
+                *elem = i as u32;
+            }
+            let refs: [&u32; 40] = (&array).into_array();
+            for (i, elem) in refs.iter().enumerate() {
+                assert!(std_::ptr::eq(*elem, &array[i]));
+            }
+        }
+    }
 }
@@ -56,6 +56,37 @@ pub use self::cloned_items::{CloneBound, CloneType, clone_this};
 /// assert_eq!([&13, &21, &34, &55].cloned_(), [13, 21, 34, 55]);
 ///
 /// ```
+///
+/// ### Nested collections
+///
+/// Tuples, arrays, `Option`s, and `Result`s clone each of their elements with `Cloned`,
+/// so nested collections are cloned recursively,
+/// until reaching the references, which are cloned with [`Clone`]
+/// (or [`ToOwned`] when the "alloc" feature is enabled).
+///
+/// ```rust
+/// use core_extensions::collections::Cloned;
+///
+/// assert_eq!(((&2, &3), &5).cloned_(), ((2, 3), 5));
+/// assert_eq!([(&8, [&13]), (&21, [&34])].cloned_(), [(8, [13]), (21, [34])]);
+///
+/// ```
+///
+/// ### Options and Results
+///
+/// ```rust
+/// use core_extensions::collections::Cloned;
+///
+/// assert_eq!(Some(&3).cloned_(), Some(3));
+/// assert_eq!(None::<&u8>.cloned_(), None);
+///
+/// assert_eq!(Ok::<&u8, &bool>(&5).cloned_(), Ok(5));
+/// assert_eq!(Err::<&u8, &bool>(&false).cloned_(), Err(false));
+///
+/// // These can be nested inside other collections
+/// assert_eq!((Some(&8), [Ok::<_, &()>(&13)]).cloned_(), (Some(8), [Ok(13)]));
+///
+/// ```
 /// 
 /// ### "alloc" feature
 ///
@@ -71,6 +102,8 @@ pub use self::cloned_items::{CloneBound, CloneType, clone_this};
 /// assert_eq!((&[3, 5, 8][..],).cloned_(), (vec![3, 5, 8],));
 /// assert_eq!((&[13, 21][..], &[34, 55][..]).cloned_(), (vec![13, 21], vec![34, 55]));
 /// 
+/// assert_eq!(Some("qux").cloned_(), Some("qux".to_string()));
+/// 
 /// ```
 ///
 /// # Implementing this trait
@@ -160,6 +193,22 @@ pub type ClonedOut<This> = <This as Cloned>::Cloned;
 /// assert_eq!([13, 21, 34, 55].into_array(), [13, 21, 34, 55]);
 ///
 /// ```
+///
+/// ### References
+///
+/// References to tuples and arrays convert into arrays of references,
+/// the [`AsArray`] trait provides a method for doing this.
+///
+/// ```rust
+/// use core_extensions::collections::IntoArray;
+///
+/// let tuple = (2, 3, 5);
+/// assert_eq!((&tuple).into_array(), [&2, &3, &5]);
+///
+/// let array = [8, 13];
+/// assert_eq!((&array).into_array(), [&8, &13]);
+///
+/// ```
 /// 
 /// # Implementing this trait
 ///
@@ -184,6 +233,8 @@ pub type ClonedOut<This> = <This as Cloned>::Cloned;
 ///
 /// ```
 ///
+/// [`AsArray`]: ./trait.AsArray.html
+///
 pub trait IntoArray {
     /// The type of the array of the same length.
     type Array;
@@ -192,4 +243,158 @@ pub trait IntoArray {
     fn into_array(self) -> Self::Array;
 }
 
+/// Constructs a fixed length collection from an array.
+///
+/// This is the inverse of [`IntoArray`].
+///
+/// # Features
+/// 
+/// Enabling the "rust_1_51" feature allows arrays of all lengths to implement this trait,
+/// otherwise it's only implemented for arrays up to 32 elements long.
+/// 
+/// # Examples
+///
+/// ### Tuples
+///
+/// ```
+/// use core_extensions::collections::FromArray;
+///
+/// assert_eq!(<(u32,)>::from_array([2]), (2,));
+/// assert_eq!(<(u32, u32)>::from_array([2, 3]), (2, 3));
+/// assert_eq!(<(u32, u32, u32)>::from_array([2, 3, 5]), (2, 3, 5));
+/// assert_eq!(<(u32, u32, u32, u32)>::from_array([2, 3, 5, 8]), (2, 3, 5, 8));
+///
+/// ```
+///
+/// ### Arrays
+///
+/// ```rust
+/// use core_extensions::collections::FromArray;
+///
+/// assert_eq!(<[u32; 1]>::from_array([13]), [13]);
+/// assert_eq!(<[u32; 2]>::from_array([13, 21]), [13, 21]);
+///
+/// ```
+///
+/// [`IntoArray`]: ./trait.IntoArray.html
+///
+pub trait FromArray {
+    /// The type of the array of the same length.
+    type Array;
+
+    /// Constructs `Self` from an array.
+    fn from_array(array: Self::Array) -> Self;
+}
+
+/// Borrows a fixed length collection as an array of references to its elements.
+///
+/// This is implemented for every type,
+/// and `each_ref_array` can be called on types whose references implement [`IntoArray`],
+/// which includes tuples whose elements are all the same type and arrays.
+///
+/// # Features
+/// 
+/// Enabling the "rust_1_51" feature allows arrays of all lengths to be used with this trait,
+/// otherwise it can only be used with arrays up to 32 elements long.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::collections::AsArray;
+///
+/// let tuple = (3u32, 5, 8);
+/// let refs: [&u32; 3] = tuple.each_ref_array();
+/// assert_eq!(refs, [&3, &5, &8]);
+///
+/// let array = ["foo", "bar"];
+/// assert_eq!(array.each_ref_array(), [&"foo", &"bar"]);
+///
+/// // `tuple` and `array` were only borrowed
+/// assert_eq!(tuple, (3, 5, 8));
+/// assert_eq!(array, ["foo", "bar"]);
+///
+/// ```
+///
+/// [`IntoArray`]: ./trait.IntoArray.html
+pub trait AsArray {
+    /// Returns an array of references to the elements of `self`.
+    #[inline(always)]
+    fn each_ref_array<'a>(&'a self) -> <&'a Self as IntoArray>::Array
+    where
+        &'a Self: IntoArray,
+    {
+        self.into_array()
+    }
+}
+
+impl<T: ?Sized> AsArray for T {}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Extension trait for tuples whose elements are all the same type.
+///
+/// This is implemented for tuples of 1 up to 12 elements.
+///
+/// For mapping over the elements of the tuple there is the [`TupleMap`] trait.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::collections::TupleExt;
+///
+/// assert_eq!((3,).fold_each(0, |acc, x| acc + x), 3);
+/// assert_eq!((3, 5, 8).fold_each(0, |acc, x| acc + x), 16);
+///
+/// let joined = ("foo", "bar", "baz").fold_each(String::new(), |mut acc, x| {
+///     if !acc.is_empty() {
+///         acc.push_str(", ");
+///     }
+///     acc.push_str(x);
+///     acc
+/// });
+/// assert_eq!(joined, "foo, bar, baz");
+///
+/// ```
+///
+/// [`TupleMap`]: ./trait.TupleMap.html
+pub trait TupleExt: Sized {
+    /// The type of every element in the tuple.
+    type Elem;
+
+    /// Folds the elements of the tuple into a single value, from the first to the last.
+    ///
+    /// This is like the [`Iterator::fold`] method.
+    ///
+    /// [`Iterator::fold`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.fold
+    fn fold_each<A, F>(self, init: A, f: F) -> A
+    where
+        F: FnMut(A, Self::Elem) -> A;
+}
+
+/// Maps the elements of a tuple whose elements are all the same type,
+/// producing a tuple of the same length.
+///
+/// This is implemented for tuples of 1 up to 12 elements,
+/// and the elements are mapped from the first to the last.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::collections::TupleMap;
+///
+/// assert_eq!((3,).map_each(|x| x * 2), (6,));
+/// assert_eq!((3, 5, 8).map_each(|x| x * 2), (6, 10, 16));
+/// assert_eq!((3, 5, 8).map_each(|x| x.to_string()), ("3".to_string(), "5".into(), "8".into()));
+///
+/// ```
+pub trait TupleMap<U>: TupleExt {
+    /// A tuple of the same length as `Self`, with `U` elements.
+    type Output;
+
+    /// Maps each element of the tuple with `f`.
+    fn map_each<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Elem) -> U;
+}
+
 ///////////////////////////////////////////////////////////////////////////////
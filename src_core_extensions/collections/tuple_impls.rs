@@ -2,17 +2,23 @@
 
 use super::{
     Cloned,
+    FromArray,
     IntoArray,
+    TupleExt,
+    TupleMap,
 };
 
 macro_rules! impl_tuple {
     (l; $($anything:tt)* )=>{ 1 };
     (a; )=>{};
     (a; $($anything:tt)* )=>{ C0 };
+    (u; $($anything:tt)* )=>{ U };
     ( ( $($tup:ident,)* ) ) => (
         impl_tuple!{cloned; all($($tup,)*) }
 
         impl_tuple!{into_array; all($($tup,)*) }
+
+        impl_tuple!{tuple_ext; all($($tup,)*) }
     );
     (cloned; all($($tup:ident,)*) ) => (
         impl<'a,$($tup),*> Cloned for ($($tup,)*)
@@ -41,6 +47,59 @@ macro_rules! impl_tuple {
                 [$($tup,)*]
             }
         }
+
+        impl<C0> FromArray for ($( impl_tuple!(a;$tup) ,)*) {
+            type Array=[C0; $( impl_tuple!(l;$tup)+ )* 0];
+
+            #[inline(always)]
+            fn from_array(array: Self::Array)->Self{
+                let [$($tup,)*]=array;
+
+                ($($tup,)*)
+            }
+        }
+
+        impl<'a, C0> IntoArray for &'a ($( impl_tuple!(a;$tup) ,)*) {
+            type Array=[&'a C0; $( impl_tuple!(l;$tup)+ )* 0];
+
+            #[inline(always)]
+            fn into_array(self)->Self::Array{
+                let ($(ref $tup,)*)=*self;
+
+                [$($tup,)*]
+            }
+        }
+    );
+    (tuple_ext; all() ) => ();
+    (tuple_ext; all($($tup:ident,)+) ) => (
+        impl<C0> TupleExt for ($( impl_tuple!(a;$tup) ,)*) {
+            type Elem = C0;
+
+            #[inline]
+            fn fold_each<A, F>(self, init: A, mut f: F) -> A
+            where
+                F: FnMut(A, C0) -> A,
+            {
+                let ($($tup,)*)=self;
+                let acc = init;
+                $( let acc = f(acc, $tup); )*
+                acc
+            }
+        }
+
+        impl<C0, U> TupleMap<U> for ($( impl_tuple!(a;$tup) ,)*) {
+            type Output = ($( impl_tuple!(u;$tup) ,)*);
+
+            #[inline]
+            fn map_each<F>(self, mut f: F) -> Self::Output
+            where
+                F: FnMut(C0) -> U,
+            {
+                let ($($tup,)*)=self;
+
+                ($( f($tup), )*)
+            }
+        }
     );
 }
 
@@ -88,6 +147,8 @@ impl_tuple! {
 mod test {
     use super::*;
 
+    use crate::collections::AsArray;
+
     #[test]
     fn cloned_core() {
         assert_eq!((&5,).cloned_(), (5,));
@@ -100,6 +161,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn cloned_nested() {
+        assert_eq!(((&2, &3), &5).cloned_(), ((2, 3), 5));
+        assert_eq!((&2, (&3, (&5, &8))).cloned_(), (2, (3, (5, 8))));
+        assert_eq!(([&2, &3], &5).cloned_(), ([2, 3], 5));
+        assert_eq!([(&2, &3), (&5, &8)].cloned_(), [(2, 3), (5, 8)]);
+        assert_eq!(
+            ([(&2, [&3]), (&5, [&8])], (&13, [(&21,)])).cloned_(),
+            ([(2, [3]), (5, [8])], (13, [(21,)])),
+        );
+        assert_eq!(
+            [[(&1, Some(&2)), (&3, None)], [(&4, Some(&5)), (&6, Some(&7))]].cloned_(),
+            [[(1, Some(2)), (3, None)], [(4, Some(5)), (6, Some(7))]],
+        );
+        assert_eq!(
+            (&&1, (&mut 2, [Ok::<_, &()>(&3)])).cloned_(),
+            (&1, (2, [Ok(3)])),
+        );
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn cloned_alloc() {
@@ -120,6 +201,70 @@ mod test {
                 "21".to_string()
             )
         );
+
+        assert_eq!(
+            (("5", ["8"]), ["13"]).cloned_(),
+            (("5".to_string(), ["8".to_string()]), ["13".to_string()]),
+        );
+    }
+
+    #[test]
+    fn from_array() {
+        assert_eq!(<(u8,)>::from_array([3]), (3,));
+        assert_eq!(<(u8, u8, u8)>::from_array([3, 5, 8]), (3, 5, 8));
+        assert_eq!(
+            <(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>::from_array(
+                [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]
+            ),
+            (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12),
+        );
+
+        let tuple = (3, 5, 8);
+        assert_eq!(<(u8, u8, u8)>::from_array(tuple.into_array()), tuple);
+    }
+
+    #[test]
+    fn each_ref_array() {
+        let tuple = (3, 5, 8);
+        let refs: [&u32; 3] = tuple.each_ref_array();
+        assert_eq!(refs, [&3, &5, &8]);
+        assert!(std_::ptr::eq(refs[2], &tuple.2));
+
+        assert_eq!((&(3,)).into_array(), [&3]);
+        assert_eq!(
+            (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12).each_ref_array(),
+            [&1, &2, &3, &4, &5, &6, &7, &8, &9, &10, &11, &12],
+        );
+    }
+
+    #[test]
+    fn map_each() {
+        assert_eq!((3,).map_each(|x| x * 2), (6,));
+        assert_eq!((3, 5, 8).map_each(|x| x * 2), (6, 10, 16));
+        assert_eq!((3, 5, 8).map_each(|x| x % 2 == 0), (false, false, true));
+        assert_eq!(
+            (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12).map_each(|x| x * x),
+            (1, 4, 9, 16, 25, 36, 49, 64, 81, 100, 121, 144),
+        );
+
+        let mut order = [0u8; 4];
+        let mut i = 0;
+        let _ = (10, 20, 30, 40).map_each(|x| {
+            order[i] = x;
+            i += 1;
+        });
+        assert_eq!(order, [10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn fold_each() {
+        assert_eq!((3,).fold_each(0, |acc, x| acc + x), 3);
+        assert_eq!((3, 5, 8).fold_each(0, |acc, x| acc + x), 16);
+        assert_eq!((3, 5, 8).fold_each(0, |acc, x| acc * 10 + x), 358);
+        assert_eq!(
+            (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12).fold_each(0u32, |acc, x| acc + x),
+            78,
+        );
     }
 
     #[test]
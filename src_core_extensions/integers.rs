@@ -5,6 +5,7 @@
 
 use std_::{cmp, hash::Hash, fmt, ops};
 
+use std_::num::{self, ParseIntError};
 use std_::time::Duration;
 
 /// Extension trait for built-in integers.
@@ -213,6 +214,448 @@ pub trait IntegerExt:
     /// ```
     ///
     fn number_of_digits(self) -> u32;
+
+    /// Returns whether `self` is a power of two.
+    ///
+    /// Unlike the inherent `is_power_of_two` method of unsigned integers,
+    /// this is also implemented for signed integers,
+    /// returning `false` for zero and negative numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0u8.is_power_of_two_(), false);
+    /// assert_eq!(1u8.is_power_of_two_(), true);
+    /// assert_eq!(2u8.is_power_of_two_(), true);
+    /// assert_eq!(3u8.is_power_of_two_(), false);
+    /// assert_eq!(128u8.is_power_of_two_(), true);
+    ///
+    /// assert_eq!(64i8.is_power_of_two_(), true);
+    /// assert_eq!(0i8.is_power_of_two_(), false);
+    /// assert_eq!((-2i8).is_power_of_two_(), false);
+    /// assert_eq!((-128i8).is_power_of_two_(), false);
+    ///
+    /// ```
+    ///
+    fn is_power_of_two_(self) -> bool;
+
+    /// Returns the smallest power of two greater than or equal to `self`,
+    /// saturating at `Self::MAX` if that power of two is not representable.
+    ///
+    /// For zero and negative numbers this returns `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0u8.next_power_of_two_(), 1);
+    /// assert_eq!(1u8.next_power_of_two_(), 1);
+    /// assert_eq!(3u8.next_power_of_two_(), 4);
+    /// assert_eq!(100u8.next_power_of_two_(), 128);
+    /// assert_eq!(129u8.next_power_of_two_(), 255);
+    /// assert_eq!(255u8.next_power_of_two_(), 255);
+    ///
+    /// assert_eq!((-100i8).next_power_of_two_(), 1);
+    /// assert_eq!(0i8.next_power_of_two_(), 1);
+    /// assert_eq!(33i8.next_power_of_two_(), 64);
+    /// assert_eq!(64i8.next_power_of_two_(), 64);
+    /// assert_eq!(65i8.next_power_of_two_(), 127);
+    /// assert_eq!(127i8.next_power_of_two_(), 127);
+    ///
+    /// ```
+    ///
+    fn next_power_of_two_(self) -> Self;
+
+    /// Rounds `self` up to the nearest multiple of `align`.
+    ///
+    /// `align` must be a power of two,
+    /// this is checked with a `debug_assert`.
+    ///
+    /// # Panics
+    ///
+    /// This panics in debug builds if `align` is not a power of two,
+    /// or if the rounded value overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(13u32.align_up_to(8), 16);
+    /// assert_eq!(16u32.align_up_to(8), 16);
+    /// assert_eq!(17u32.align_up_to(8), 24);
+    /// assert_eq!(0u32.align_up_to(8), 0);
+    /// assert_eq!(5u32.align_up_to(1), 5);
+    ///
+    /// assert_eq!(100usize.align_up_to(64), 128);
+    /// assert_eq!((-13i8).align_up_to(8), -8);
+    ///
+    /// ```
+    ///
+    #[inline]
+    fn align_up_to(self, align: Self) -> Self {
+        debug_assert!(align.is_power_of_two_(), "align must be a power of two");
+        let mask = align - Self::ONE;
+        (self + mask) & !mask
+    }
+
+    /// Rounds `self` down to the nearest multiple of `align`.
+    ///
+    /// `align` must be a power of two,
+    /// this is checked with a `debug_assert`.
+    ///
+    /// # Panics
+    ///
+    /// This panics in debug builds if `align` is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(13u32.align_down_to(8), 8);
+    /// assert_eq!(16u32.align_down_to(8), 16);
+    /// assert_eq!(7u32.align_down_to(8), 0);
+    /// assert_eq!(5u32.align_down_to(1), 5);
+    ///
+    /// assert_eq!(100usize.align_down_to(64), 64);
+    /// assert_eq!((-13i8).align_down_to(8), -16);
+    ///
+    /// ```
+    ///
+    #[inline]
+    fn align_down_to(self, align: Self) -> Self {
+        debug_assert!(align.is_power_of_two_(), "align must be a power of two");
+        self & !(align - Self::ONE)
+    }
+
+    /// Rotates the bits of `self` to the left by `n` bits,
+    /// wrapping the truncated bits around to the end.
+    ///
+    /// This delegates to the inherent `rotate_left` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0b1000_0011u8.rotate_left_(1), 0b0000_0111);
+    /// assert_eq!(0b1000_0011u8.rotate_left_(3), 0b0001_1100);
+    /// assert_eq!(0b1000_0011u8.rotate_left_(8), 0b1000_0011);
+    /// assert_eq!(0b1000_0011u8.rotate_left_(5), 0b1000_0011u8.rotate_left(5));
+    ///
+    /// assert_eq!((-128i8).rotate_left_(1), 1);
+    ///
+    /// ```
+    ///
+    fn rotate_left_(self, n: u32) -> Self;
+
+    /// Rotates the bits of `self` to the right by `n` bits,
+    /// wrapping the truncated bits around to the start.
+    ///
+    /// This delegates to the inherent `rotate_right` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0b1000_0011u8.rotate_right_(1), 0b1100_0001);
+    /// assert_eq!(0b1000_0011u8.rotate_right_(3), 0b0111_0000);
+    /// assert_eq!(0b1000_0011u8.rotate_right_(8), 0b1000_0011);
+    /// assert_eq!(0b1000_0011u8.rotate_right_(5), 0b1000_0011u8.rotate_right(5));
+    ///
+    /// assert_eq!(1i8.rotate_right_(1), -128);
+    ///
+    /// ```
+    ///
+    fn rotate_right_(self, n: u32) -> Self;
+
+    /// Returns the amount of ones in the binary representation of `self`.
+    ///
+    /// This delegates to the inherent `count_ones` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0b0101_1000u8.count_ones_(), 3);
+    /// assert_eq!(0u16.count_ones_(), 0);
+    /// assert_eq!((-1i32).count_ones_(), 32);
+    /// assert_eq!((-128i8).count_ones_(), 1);
+    ///
+    /// ```
+    ///
+    fn count_ones_(self) -> u32;
+
+    /// Returns the amount of zeros in the binary representation of `self`.
+    ///
+    /// This delegates to the inherent `count_zeros` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0b0101_1000u8.count_zeros_(), 5);
+    /// assert_eq!(0u16.count_zeros_(), 16);
+    /// assert_eq!((-1i32).count_zeros_(), 0);
+    /// assert_eq!((-128i8).count_zeros_(), 7);
+    ///
+    /// ```
+    ///
+    fn count_zeros_(self) -> u32;
+
+    /// Returns the amount of leading zeros in the binary representation of `self`.
+    ///
+    /// This delegates to the inherent `leading_zeros` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0b0101_1000u8.leading_zeros_(), 1);
+    /// assert_eq!(0u16.leading_zeros_(), 16);
+    /// assert_eq!(1i32.leading_zeros_(), 31);
+    /// assert_eq!((-1i8).leading_zeros_(), 0);
+    ///
+    /// ```
+    ///
+    fn leading_zeros_(self) -> u32;
+
+    /// Returns the amount of trailing zeros in the binary representation of `self`.
+    ///
+    /// This delegates to the inherent `trailing_zeros` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0b0101_1000u8.trailing_zeros_(), 3);
+    /// assert_eq!(0u16.trailing_zeros_(), 16);
+    /// assert_eq!(1i32.trailing_zeros_(), 0);
+    /// assert_eq!((-128i8).trailing_zeros_(), 7);
+    ///
+    /// ```
+    ///
+    fn trailing_zeros_(self) -> u32;
+
+    /// Returns the amount of leading ones in the binary representation of `self`.
+    ///
+    /// This is equivalent to the inherent `leading_ones` method of integers,
+    /// which requires Rust 1.46.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0b1101_1000u8.leading_ones_(), 2);
+    /// assert_eq!(0u16.leading_ones_(), 0);
+    /// assert_eq!((-1i32).leading_ones_(), 32);
+    /// assert_eq!((-2i8).leading_ones_(), 7);
+    ///
+    /// ```
+    ///
+    fn leading_ones_(self) -> u32;
+
+    /// Returns the amount of trailing ones in the binary representation of `self`.
+    ///
+    /// This is equivalent to the inherent `trailing_ones` method of integers,
+    /// which requires Rust 1.46.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(0b0101_1011u8.trailing_ones_(), 2);
+    /// assert_eq!(0u16.trailing_ones_(), 0);
+    /// assert_eq!((-1i32).trailing_ones_(), 32);
+    /// assert_eq!(127i8.trailing_ones_(), 7);
+    ///
+    /// ```
+    ///
+    fn trailing_ones_(self) -> u32;
+
+    /// Checked integer addition, returning `None` if it overflows.
+    ///
+    /// This delegates to the inherent `checked_add` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(100u8.checked_add_(155), Some(255));
+    /// assert_eq!(100u8.checked_add_(156), None);
+    /// assert_eq!((-100i8).checked_add_(-28), Some(-128));
+    /// assert_eq!((-100i8).checked_add_(-29), None);
+    ///
+    /// ```
+    ///
+    fn checked_add_(self, rhs: Self) -> Option<Self>;
+
+    /// Checked integer subtraction, returning `None` if it overflows.
+    ///
+    /// This delegates to the inherent `checked_sub` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(100u8.checked_sub_(100), Some(0));
+    /// assert_eq!(100u8.checked_sub_(101), None);
+    /// assert_eq!(100i8.checked_sub_(-27), Some(127));
+    /// assert_eq!(100i8.checked_sub_(-28), None);
+    ///
+    /// ```
+    ///
+    fn checked_sub_(self, rhs: Self) -> Option<Self>;
+
+    /// Checked integer multiplication, returning `None` if it overflows.
+    ///
+    /// This delegates to the inherent `checked_mul` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(15u8.checked_mul_(17), Some(255));
+    /// assert_eq!(16u8.checked_mul_(16), None);
+    /// assert_eq!((-64i8).checked_mul_(2), Some(-128));
+    /// assert_eq!(64i8.checked_mul_(2), None);
+    ///
+    /// ```
+    ///
+    fn checked_mul_(self, rhs: Self) -> Option<Self>;
+
+    /// Checked integer division, 
+    /// returning `None` if `rhs` is zero or the division overflows.
+    ///
+    /// This delegates to the inherent `checked_div` method of integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(100u8.checked_div_(7), Some(14));
+    /// assert_eq!(100u8.checked_div_(0), None);
+    /// assert_eq!((-128i8).checked_div_(2), Some(-64));
+    /// assert_eq!((-128i8).checked_div_(-1), None);
+    ///
+    /// ```
+    ///
+    fn checked_div_(self, rhs: Self) -> Option<Self>;
+
+    /// Computes `self * mul / div`, 
+    /// using a wider intermediate value so that the multiplication can't overflow.
+    ///
+    /// The result is rounded towards zero, like the `/` operator.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `div` is zero,
+    /// or if the final result is out of the range of `Self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// // `200 * 200` doesn't fit in a `u8`, but the final result does.
+    /// assert_eq!(200u8.mul_div(200, 250), 160);
+    /// assert_eq!(200u8.mul_div(100, 200), 100);
+    ///
+    /// assert_eq!(100i8.mul_div(-100, 99), -101);
+    /// assert_eq!((-128i8).mul_div(-128, -128), -128);
+    ///
+    /// assert_eq!(<u128 as IntegerExt>::MAX.mul_div(3, 4), 255211775190703847597530955573826158591);
+    ///
+    /// ```
+    ///
+    fn mul_div(self, mul: Self, div: Self) -> Self;
+
+    /// Returns the absolute value of `self`, saturating at `Self::MAX`
+    /// if it is not representable.
+    ///
+    /// For unsigned integers this returns `self` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(100i8.saturating_abs_(), 100);
+    /// assert_eq!((-100i8).saturating_abs_(), 100);
+    /// assert_eq!((-128i8).saturating_abs_(), 127);
+    ///
+    /// assert_eq!(0u8.saturating_abs_(), 0);
+    /// assert_eq!(255u8.saturating_abs_(), 255);
+    ///
+    /// ```
+    ///
+    fn saturating_abs_(self) -> Self;
+
+    /// Returns the absolute value of `self`, 
+    /// returning `None` if it is not representable.
+    ///
+    /// For unsigned integers this returns `Some(self)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(100i8.checked_abs_(), Some(100));
+    /// assert_eq!((-100i8).checked_abs_(), Some(100));
+    /// assert_eq!((-128i8).checked_abs_(), None);
+    ///
+    /// assert_eq!(0u8.checked_abs_(), Some(0));
+    /// assert_eq!(255u8.checked_abs_(), Some(255));
+    ///
+    /// ```
+    ///
+    fn checked_abs_(self) -> Option<Self>;
+
+    /// Parses an integer from a string in the given `radix`,
+    /// by delegating to the inherent `from_str_radix` associated function of integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the `2..=36` range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::IntegerExt;
+    ///
+    /// assert_eq!(u8::from_str_radix_("ff", 16), Ok(255));
+    /// assert_eq!(u8::from_str_radix_("101", 2), Ok(5));
+    /// assert_eq!(i16::from_str_radix_("-zz", 36), Ok(-1295));
+    ///
+    /// assert!(u8::from_str_radix_("100", 16).is_err());
+    /// assert!(u8::from_str_radix_("-1", 10).is_err());
+    /// assert!(u8::from_str_radix_("", 10).is_err());
+    ///
+    /// fn parse_hex<T: IntegerExt>(s: &str) -> T {
+    ///     T::from_str_radix_(s.trim_start_matches("0x"), 16).unwrap()
+    /// }
+    ///
+    /// assert_eq!(parse_hex::<u32>("0xdead"), 0xDEAD);
+    ///
+    /// ```
+    ///
+    fn from_str_radix_(s: &str, radix: u32) -> Result<Self, ParseIntError>;
 }
 
 /// Converts an integer to a Duration of the unit.
@@ -379,16 +822,78 @@ impl Sign {
     /// ```
     ///
     #[inline]
-    pub const fn sign_string(self) -> &'static str {
-        cfg_if!{
-            (feature = "rust_1_46") {
-                match self {
-                    Sign::Positive => "",
-                    Sign::Negative => "-",
-                }
-            } else {
-                ["", "-"][self as usize]
-            }
+    pub const fn sign_string(self) -> &'static str {
+        cfg_if!{
+            (feature = "rust_1_46") {
+                match self {
+                    Sign::Positive => "",
+                    Sign::Negative => "-",
+                }
+            } else {
+                ["", "-"][self as usize]
+            }
+        }
+    }
+
+    /// Converts an `Ordering` into a `Sign`,
+    /// returning `None` for `Ordering::Equal`.
+    ///
+    /// `Sign` can be converted back into an `Ordering` with its
+    /// `From<Sign> for Ordering` impl.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::integers::Sign;
+    ///
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Sign::from_cmp(Ordering::Less), Some(Sign::Negative));
+    /// assert_eq!(Sign::from_cmp(Ordering::Equal), None);
+    /// assert_eq!(Sign::from_cmp(Ordering::Greater), Some(Sign::Positive));
+    ///
+    /// assert_eq!(Sign::from_cmp(3.cmp(&5)), Some(Sign::Negative));
+    ///
+    /// assert_eq!(Ordering::from(Sign::Negative), Ordering::Less);
+    /// assert_eq!(Ordering::from(Sign::Positive), Ordering::Greater);
+    /// ```
+    ///
+    #[inline]
+    pub fn from_cmp(ordering: cmp::Ordering) -> Option<Self> {
+        match ordering {
+            cmp::Ordering::Less => Some(Sign::Negative),
+            cmp::Ordering::Equal => None,
+            cmp::Ordering::Greater => Some(Sign::Positive),
+        }
+    }
+
+    /// Returns `1` for `Positive` and `-1` for `Negative`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::integers::{IntegerExt, Sign};
+    ///
+    /// assert_eq!(Sign::Positive.to_multiplier(), 1);
+    /// assert_eq!(Sign::Negative.to_multiplier(), -1);
+    ///
+    /// assert_eq!(Sign::Negative.to_multiplier() as i32 * 10, -10);
+    /// assert_eq!((-7i8).get_sign().to_multiplier() * 3, -3);
+    /// ```
+    ///
+    #[inline]
+    pub const fn to_multiplier(self) -> i8 {
+        1 - 2 * (self as i8)
+    }
+}
+
+impl From<Sign> for cmp::Ordering {
+    /// Converts `Positive` to `Greater` and `Negative` to `Less`.
+    #[inline]
+    fn from(sign: Sign) -> Self {
+        match sign {
+            Sign::Positive => cmp::Ordering::Greater,
+            Sign::Negative => cmp::Ordering::Less,
         }
     }
 }
@@ -401,6 +906,69 @@ impl fmt::Display for Sign {
 
 //---------------------------------- IMPLS -------------------------------------------
 
+/// Computes `a * b / div` with a 256-bit intermediate product,
+/// returning `None` if the quotient doesn't fit in a `u128`.
+fn mul_div_u128(a: u128, b: u128, div: u128) -> Option<u128> {
+    assert!(div != 0, "attempt to divide by zero in `IntegerExt::mul_div`");
+
+    if let Some(prod) = a.checked_mul(b) {
+        return Some(prod / div);
+    }
+
+    const LOW_MASK: u128 = (1 << 64) - 1;
+    let (a_lo, a_hi) = (a & LOW_MASK, a >> 64);
+    let (b_lo, b_hi) = (b & LOW_MASK, b >> 64);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (lo_hi & LOW_MASK) + (hi_lo & LOW_MASK);
+    let low = (lo_lo & LOW_MASK) | (mid << 64);
+    let high = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+
+    if high >= div {
+        return None;
+    }
+
+    // Bitwise long division of `high:low` by `div`,
+    // `rem` is always less than `div` at the start of each iteration.
+    let mut rem = high;
+    let mut quot = 0u128;
+    for bit in (0..128).rev() {
+        let carry = rem >> 127;
+        rem = (rem << 1) | ((low >> bit) & 1);
+        if carry == 1 || rem >= div {
+            rem = rem.wrapping_sub(div);
+            quot |= 1 << bit;
+        }
+    }
+    Some(quot)
+}
+
+/// Computes `a * b / div` with a 256-bit intermediate product,
+/// returning `None` if the quotient doesn't fit in an `i128`.
+fn mul_div_i128(a: i128, b: i128, div: i128) -> Option<i128> {
+    let negative = (a < 0) ^ (b < 0) ^ (div < 0);
+    let abs = mul_div_u128(a.abs_unsigned(), b.abs_unsigned(), div.abs_unsigned())?;
+    let max = <i128 as IntegerExt>::MAX as u128;
+
+    if !negative && abs <= max {
+        Some(abs as i128)
+    } else if negative && abs <= max + 1 {
+        Some((abs as i128).wrapping_neg())
+    } else {
+        None
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn mul_div_out_of_range() -> ! {
+    panic!("the result of `IntegerExt::mul_div` is out of range")
+}
+
 macro_rules! impl_absolute_unsigned_numbers {
     (from_u8;8,signed)=>{
         #[inline(always)]
@@ -466,6 +1034,58 @@ macro_rules! impl_absolute_unsigned_numbers {
         fn power(self,n:u32)->Self{
             self.pow(n)
         }
+        #[inline]
+        fn from_str_radix_(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+            <$This>::from_str_radix(s, radix)
+        }
+        #[inline]
+        fn rotate_left_(self,n:u32)->Self{
+            self.rotate_left(n)
+        }
+        #[inline]
+        fn rotate_right_(self,n:u32)->Self{
+            self.rotate_right(n)
+        }
+        #[inline]
+        fn count_ones_(self)->u32{
+            self.count_ones()
+        }
+        #[inline]
+        fn count_zeros_(self)->u32{
+            self.count_zeros()
+        }
+        #[inline]
+        fn leading_zeros_(self)->u32{
+            self.leading_zeros()
+        }
+        #[inline]
+        fn trailing_zeros_(self)->u32{
+            self.trailing_zeros()
+        }
+        #[inline]
+        fn leading_ones_(self)->u32{
+            (!self).leading_zeros()
+        }
+        #[inline]
+        fn trailing_ones_(self)->u32{
+            (!self).trailing_zeros()
+        }
+        #[inline]
+        fn checked_add_(self,rhs:Self)->Option<Self>{
+            self.checked_add(rhs)
+        }
+        #[inline]
+        fn checked_sub_(self,rhs:Self)->Option<Self>{
+            self.checked_sub(rhs)
+        }
+        #[inline]
+        fn checked_mul_(self,rhs:Self)->Option<Self>{
+            self.checked_mul(rhs)
+        }
+        #[inline]
+        fn checked_div_(self,rhs:Self)->Option<Self>{
+            self.checked_div(rhs)
+        }
 
     };
 
@@ -502,6 +1122,41 @@ macro_rules! impl_absolute_unsigned_numbers {
             }
             impl_absolute_unsigned_numbers!{from_u8;$bits,signed}
             impl_absolute_unsigned_numbers!{from_i8;signed}
+
+            #[inline]
+            fn is_power_of_two_(self) -> bool {
+                self > 0 && (self as $tuns).is_power_of_two()
+            }
+
+            #[inline]
+            fn next_power_of_two_(self) -> Self {
+                if self <= 1 {
+                    return 1;
+                }
+                match (self as $tuns).checked_next_power_of_two() {
+                    Some(x) if x <= <Self as IntegerExt>::MAX as $tuns => x as Self,
+                    _ => <Self as IntegerExt>::MAX,
+                }
+            }
+
+            #[inline]
+            fn saturating_abs_(self) -> Self {
+                self.checked_abs().unwrap_or(<Self as IntegerExt>::MAX)
+            }
+
+            #[inline]
+            fn checked_abs_(self) -> Option<Self> {
+                self.checked_abs()
+            }
+
+            #[inline]
+            fn mul_div(self, mul: Self, div: Self) -> Self {
+                match mul_div_i128(self as i128, mul as i128, div as i128) {
+                    Some(x) if <Self as IntegerExt>::MIN as i128 <= x
+                        && x <= <Self as IntegerExt>::MAX as i128 => x as Self,
+                    _ => mul_div_out_of_range(),
+                }
+            }
         }
 
         $(#[$meta])*
@@ -528,6 +1183,34 @@ macro_rules! impl_absolute_unsigned_numbers {
 
             impl_absolute_unsigned_numbers!{from_u8;$bits,unsigned}
             impl_absolute_unsigned_numbers!{from_i8;unsigned}
+
+            #[inline]
+            fn is_power_of_two_(self) -> bool {
+                self.is_power_of_two()
+            }
+
+            #[inline]
+            fn next_power_of_two_(self) -> Self {
+                self.checked_next_power_of_two().unwrap_or(<Self as IntegerExt>::MAX)
+            }
+
+            #[inline]
+            fn saturating_abs_(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn checked_abs_(self) -> Option<Self> {
+                Some(self)
+            }
+
+            #[inline]
+            fn mul_div(self, mul: Self, div: Self) -> Self {
+                match mul_div_u128(self as u128, mul as u128, div as u128) {
+                    Some(x) if x <= <Self as IntegerExt>::MAX as u128 => x as Self,
+                    _ => mul_div_out_of_range(),
+                }
+            }
         }
 
     )*}
@@ -553,6 +1236,168 @@ impl_absolute_unsigned_numbers!(
     [isize,usize,bits=delegate,cast=UWord,]
 );
 
+//------------------------------------------------------------------------------------
+
+/// Extension trait for the `NonZero*` integer types.
+///
+/// This allows generic code to work over non-zero integers,
+/// which don't implement [`IntegerExt`] because they lack arithmetic operators.
+///
+/// [`IntegerExt`]: ./trait.IntegerExt.html
+///
+/// # Example
+///
+/// ```
+/// use core_extensions::integers::NonZeroExt;
+///
+/// use std::num::{NonZeroU8, NonZeroI32};
+///
+/// fn scale<N: NonZeroExt>(n: N, by: N) -> Option<N::Integer> {
+///     n.checked_mul_(by).map(NonZeroExt::get_)
+/// }
+///
+/// let three = NonZeroU8::new_(3).unwrap();
+/// assert_eq!(scale(three, three), Some(9));
+/// assert_eq!(scale(NonZeroU8::new_(16).unwrap(), NonZeroU8::new_(16).unwrap()), None);
+///
+/// let neg = NonZeroI32::new_(-5).unwrap();
+/// assert_eq!(scale(neg, NonZeroI32::new_(7).unwrap()), Some(-35));
+///
+/// ```
+pub trait NonZeroExt:
+    'static
+    + cmp::Eq
+    + cmp::Ord
+    + Copy
+    + fmt::Binary
+    + fmt::Debug
+    + fmt::Display
+    + fmt::LowerHex
+    + fmt::Octal
+    + fmt::UpperHex
+    + Hash
+    + Send
+    + Sync
+{
+    /// The integer type that this wraps.
+    type Integer: IntegerExt;
+
+    /// Constructs this from an integer, returning `None` if it's zero.
+    ///
+    /// This delegates to the inherent `new` constructor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::integers::NonZeroExt;
+    ///
+    /// use std::num::{NonZeroU8, NonZeroI32};
+    ///
+    /// assert_eq!(NonZeroU8::new_(5), NonZeroU8::new(5));
+    /// assert_eq!(NonZeroU8::new_(0), None);
+    /// assert_eq!(NonZeroI32::new_(-5), NonZeroI32::new(-5));
+    ///
+    /// ```
+    fn new_(n: Self::Integer) -> Option<Self>;
+
+    /// Gets the wrapped integer.
+    ///
+    /// This delegates to the inherent `get` method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::integers::NonZeroExt;
+    ///
+    /// use std::num::{NonZeroU8, NonZeroI32};
+    ///
+    /// assert_eq!(NonZeroU8::new(5).unwrap().get_(), 5u8);
+    /// assert_eq!(NonZeroI32::new(-5).unwrap().get_(), -5i32);
+    ///
+    /// ```
+    fn get_(self) -> Self::Integer;
+
+    /// Adds an integer to `self`,
+    /// returning `None` if the result overflows or is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::integers::NonZeroExt;
+    ///
+    /// use std::num::{NonZeroU8, NonZeroI32};
+    ///
+    /// let five = NonZeroU8::new(5).unwrap();
+    /// assert_eq!(five.checked_add_(10), NonZeroU8::new(15));
+    /// assert_eq!(five.checked_add_(251), None);
+    ///
+    /// let neg = NonZeroI32::new(-5).unwrap();
+    /// assert_eq!(neg.checked_add_(3), NonZeroI32::new(-2));
+    /// assert_eq!(neg.checked_add_(5), None);
+    ///
+    /// ```
+    #[inline]
+    fn checked_add_(self, rhs: Self::Integer) -> Option<Self> {
+        self.get_().checked_add_(rhs).and_then(Self::new_)
+    }
+
+    /// Multiplies `self` by another non-zero integer,
+    /// returning `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::integers::NonZeroExt;
+    ///
+    /// use std::num::{NonZeroU8, NonZeroI32};
+    ///
+    /// let five = NonZeroU8::new(5).unwrap();
+    /// assert_eq!(five.checked_mul_(five), NonZeroU8::new(25));
+    /// assert_eq!(five.checked_mul_(NonZeroU8::new(52).unwrap()), None);
+    ///
+    /// let neg = NonZeroI32::new(-5).unwrap();
+    /// assert_eq!(neg.checked_mul_(neg), NonZeroI32::new(25));
+    ///
+    /// ```
+    #[inline]
+    fn checked_mul_(self, rhs: Self) -> Option<Self> {
+        self.get_().checked_mul_(rhs.get_()).and_then(Self::new_)
+    }
+}
+
+macro_rules! impl_nonzero_ext {
+    ($($nonzero:ident = $int:ty,)*) => {$(
+        impl NonZeroExt for num::$nonzero {
+            type Integer = $int;
+
+            #[inline]
+            fn new_(n: $int) -> Option<Self> {
+                num::$nonzero::new(n)
+            }
+
+            #[inline]
+            fn get_(self) -> $int {
+                self.get()
+            }
+        }
+    )*}
+}
+
+impl_nonzero_ext! {
+    NonZeroU8 = u8,
+    NonZeroU16 = u16,
+    NonZeroU32 = u32,
+    NonZeroU64 = u64,
+    NonZeroU128 = u128,
+    NonZeroUsize = usize,
+    NonZeroI8 = i8,
+    NonZeroI16 = i16,
+    NonZeroI32 = i32,
+    NonZeroI64 = i64,
+    NonZeroI128 = i128,
+    NonZeroIsize = isize,
+}
+
 //---------------------------------- TESTS  -------------------------------------------
 
 #[cfg(test)]
@@ -627,4 +1472,248 @@ mod tests {
     fn associated_constants() {
         check_assoc_consts!(i8, u8, i16, u16, i32, u32, u64, i64, usize, isize, u128, i128);
     }
+
+    macro_rules! check_powers_of_two {
+        ($($ty:ty),*) => {
+            $({
+                let max = <$ty as IntegerExt>::MAX;
+                let high_bit = (max >> 1) + 1;
+
+                assert!(!(0 as $ty).is_power_of_two_());
+                assert!((1 as $ty).is_power_of_two_());
+                assert!((2 as $ty).is_power_of_two_());
+                assert!(!(3 as $ty).is_power_of_two_());
+                assert!(high_bit.is_power_of_two_());
+                assert!(!max.is_power_of_two_());
+
+                assert_eq!((0 as $ty).next_power_of_two_(), 1);
+                assert_eq!((1 as $ty).next_power_of_two_(), 1);
+                assert_eq!((5 as $ty).next_power_of_two_(), 8);
+                assert_eq!(high_bit.next_power_of_two_(), high_bit);
+                assert_eq!((high_bit + 1).next_power_of_two_(), max);
+                assert_eq!(max.next_power_of_two_(), max);
+
+                let min = <$ty as IntegerExt>::MIN;
+                assert!(!min.is_power_of_two_());
+                assert_eq!(min.next_power_of_two_(), 1);
+            })*
+        };
+    }
+
+    macro_rules! check_abs {
+        ($($signed:ty, $unsigned:ty),*) => {
+            $({
+                let min = <$signed as IntegerExt>::MIN;
+                let max = <$signed as IntegerExt>::MAX;
+                assert_eq!(min.saturating_abs_(), max);
+                assert_eq!(min.checked_abs_(), None);
+                assert_eq!((min + 1).saturating_abs_(), max);
+                assert_eq!((min + 1).checked_abs_(), Some(max));
+                assert_eq!((-5 as $signed).saturating_abs_(), 5);
+                assert_eq!((-5 as $signed).checked_abs_(), Some(5));
+                assert_eq!((0 as $signed).checked_abs_(), Some(0));
+
+                let umax = <$unsigned as IntegerExt>::MAX;
+                assert_eq!(umax.saturating_abs_(), umax);
+                assert_eq!(umax.checked_abs_(), Some(umax));
+                assert_eq!((0 as $unsigned).saturating_abs_(), 0);
+            })*
+        };
+    }
+
+    macro_rules! check_bit_counts {
+        ($($ty:ty),*) => {
+            $({
+                let values: [$ty; 8] = [
+                    0,
+                    1,
+                    5,
+                    100,
+                    <$ty as IntegerExt>::MIN,
+                    <$ty as IntegerExt>::MAX,
+                    <$ty as IntegerExt>::MIN + 1,
+                    <$ty as IntegerExt>::MAX - 1,
+                ];
+                for &n in &values {
+                    assert_eq!(n.count_ones_(), n.count_ones());
+                    assert_eq!(n.count_zeros_(), n.count_zeros());
+                    assert_eq!(n.leading_zeros_(), n.leading_zeros());
+                    assert_eq!(n.trailing_zeros_(), n.trailing_zeros());
+                    let bits = (std_::mem::size_of::<$ty>() * 8) as u32;
+                    assert_eq!(n.count_ones_() + n.count_zeros_(), bits);
+
+                    let is_one = |i: u32| (n >> i) & 1 == 1;
+                    let leading = (0..bits).rev().take_while(|&i| is_one(i)).count() as u32;
+                    let trailing = (0..bits).take_while(|&i| is_one(i)).count() as u32;
+                    assert_eq!(n.leading_ones_(), leading);
+                    assert_eq!(n.trailing_ones_(), trailing);
+
+                    #[cfg(feature = "rust_1_46")]
+                    {
+                        assert_eq!(n.leading_ones_(), n.leading_ones());
+                        assert_eq!(n.trailing_ones_(), n.trailing_ones());
+                    }
+                }
+            })*
+        };
+    }
+
+    macro_rules! check_checked_ops {
+        ($($ty:ty),*) => {
+            $({
+                let min = <$ty as IntegerExt>::MIN;
+                let max = <$ty as IntegerExt>::MAX;
+                let one: $ty = 1;
+                let two: $ty = 2;
+
+                assert_eq!(max.checked_add_(one), None);
+                assert_eq!((max - one).checked_add_(one), Some(max));
+                assert_eq!(min.checked_sub_(one), None);
+                assert_eq!((min + one).checked_sub_(one), Some(min));
+                assert_eq!(max.checked_mul_(two), None);
+                assert_eq!((max / two).checked_mul_(two), Some(max - max % two));
+                assert_eq!(max.checked_div_(0), None);
+                assert_eq!(max.checked_div_(one), Some(max));
+                assert_eq!(max.checked_div_(max), Some(one));
+            })*
+        };
+    }
+
+    #[test]
+    fn checked_ops() {
+        check_checked_ops!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+        assert_eq!((-128i8).checked_div_(-1), None);
+        assert_eq!(<i64 as IntegerExt>::MIN.checked_div_(-1), None);
+        assert_eq!((-100i32).checked_mul_(-100), Some(10000));
+    }
+
+    #[test]
+    fn nonzero_ext() {
+        use std_::num::{NonZeroI32, NonZeroU8};
+
+        fn add<N: NonZeroExt>(n: N, rhs: N::Integer) -> Option<N::Integer> {
+            n.checked_add_(rhs).map(NonZeroExt::get_)
+        }
+
+        fn mul<N: NonZeroExt>(n: N, rhs: N) -> Option<N::Integer> {
+            n.checked_mul_(rhs).map(NonZeroExt::get_)
+        }
+
+        let u = |n: u8| NonZeroU8::new_(n).unwrap();
+        let i = |n: i32| NonZeroI32::new_(n).unwrap();
+
+        assert_eq!(NonZeroU8::new_(0), None);
+        assert_eq!(NonZeroI32::new_(0), None);
+        assert_eq!(u(200).get_(), 200);
+        assert_eq!(i(-200).get_(), -200);
+
+        assert_eq!(add(u(200), 55), Some(255));
+        assert_eq!(add(u(200), 56), None);
+        assert_eq!(add(u(1), 0), Some(1));
+        assert_eq!(mul(u(15), u(17)), Some(255));
+        assert_eq!(mul(u(16), u(16)), None);
+
+        assert_eq!(add(i(-200), 200), None);
+        assert_eq!(add(i(<i32 as IntegerExt>::MAX), 1), None);
+        assert_eq!(add(i(<i32 as IntegerExt>::MIN), -1), None);
+        assert_eq!(add(i(-200), 100), Some(-100));
+        assert_eq!(mul(i(-1), i(<i32 as IntegerExt>::MIN)), None);
+        assert_eq!(mul(i(-3), i(-7)), Some(21));
+    }
+
+    #[test]
+    fn bit_counts() {
+        check_bit_counts!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+    }
+
+    #[test]
+    fn absolute_values() {
+        check_abs!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+    }
+
+    #[test]
+    fn powers_of_two() {
+        check_powers_of_two!(i8, u8, i16, u16, i32, u32, u64, i64, usize, isize, u128, i128);
+    }
+
+    #[test]
+    fn align_to() {
+        assert_eq!(13u8.align_up_to(8), 16);
+        assert_eq!(13u8.align_down_to(8), 8);
+        assert_eq!(13i64.align_up_to(8), 16);
+        assert_eq!(13i64.align_down_to(8), 8);
+        assert_eq!(13usize.align_up_to(8), 16);
+        assert_eq!(13usize.align_down_to(8), 8);
+        assert_eq!(13u128.align_up_to(16), 16);
+        assert_eq!(13u128.align_down_to(16), 0);
+
+        for &align in &[1u32, 2, 4, 8, 16, 32] {
+            for n in 0u32..100 {
+                let up = n.align_up_to(align);
+                let down = n.align_down_to(align);
+                assert_eq!(up % align, 0);
+                assert_eq!(down % align, 0);
+                assert!(down <= n && n <= up);
+                assert!(up - down == 0 || up - down == align);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn align_up_to_non_power_of_two() {
+        13u32.align_up_to(6);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn align_down_to_non_power_of_two() {
+        13u32.align_down_to(0);
+    }
+
+    #[test]
+    fn mul_div() {
+        assert_eq!(200u8.mul_div(200, 250), 160);
+        assert_eq!(255u8.mul_div(255, 255), 255);
+        assert_eq!(0u8.mul_div(255, 1), 0);
+        assert_eq!(7u16.mul_div(3, 2), 10);
+
+        assert_eq!(100i8.mul_div(100, 127), 78);
+        assert_eq!(100i8.mul_div(-100, 99), -101);
+        assert_eq!((-100i8).mul_div(-100, 99), 101);
+        assert_eq!((-128i8).mul_div(-128, -128), -128);
+
+        let u128_max = <u128 as IntegerExt>::MAX;
+        assert_eq!(u128_max.mul_div(u128_max, u128_max), u128_max);
+        assert_eq!(u128_max.mul_div(3, 4), u128_max / 4 * 3 + 2);
+        assert_eq!((1u128 << 100).mul_div(1 << 100, 1 << 90), 1 << 110);
+
+        let i128_max = <i128 as IntegerExt>::MAX;
+        let i128_min = <i128 as IntegerExt>::MIN;
+        assert_eq!(i128_max.mul_div(i128_max, i128_max), i128_max);
+        assert_eq!(i128_min.mul_div(i128_min, i128_min), i128_min);
+        assert_eq!(i128_min.mul_div(i128_max, i128_max), i128_min);
+        assert_eq!(i128_max.mul_div(-3, 4), -(i128_max / 4 * 3 + 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_div_out_of_range() {
+        200u8.mul_div(200, 100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_div_negative_out_of_range() {
+        (-128i8).mul_div(1, -1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_div_by_zero() {
+        3u32.mul_div(5, 0);
+    }
 }
@@ -1,11 +1,57 @@
 //! Iterator adaptors and constructors.
 
 use std_::{
-    cmp::Ordering,
-    iter::{Product, Sum},
+    cmp::{self, Ordering},
+    fmt,
+    iter::{Fuse, Peekable, Product, Sum},
+    ops::Add,
     mem,
 };
 
+#[cfg(feature = "std")]
+use std_::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+#[cfg(feature = "rust_1_51")]
+mod collect_array;
+
+
+// Declared with a macro so that Rust versions without const generics
+// never have to parse these methods.
+#[cfg(feature = "rust_1_51")]
+macro_rules! iterator_ext_const_generic_methods {() => {
+    /// Collects the first `N` items of the iterator into an array.
+    ///
+    /// Returns `None` if the iterator has fewer than `N` items.
+    /// Items after the first `N` are ignored, they're not consumed from the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!((0..10).collect_array::<3>(), Some([0, 1, 2]));
+    /// assert_eq!((0..3).collect_array::<3>(), Some([0, 1, 2]));
+    /// assert_eq!((0..2).collect_array::<3>(), None);
+    ///
+    /// let mut iter = "foo bar baz qux".split(' ');
+    /// let [first, second] = iter.by_ref().collect_array().unwrap();
+    /// assert_eq!((first, second), ("foo", "bar"));
+    /// assert_eq!(iter.collect::<Vec<_>>(), ["baz", "qux"]);
+    ///
+    /// ```
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+    #[inline(always)]
+    fn collect_array<const N: usize>(self) -> Option<[Self::Item; N]>
+    where
+        Self: Sized,
+    {
+        self::collect_array::collect_array(self)
+    }
+}}
+
 
 /// A version of [`std::iter::OnceWith`] usable in Rust 1.41.0.
 ///
@@ -217,165 +263,1944 @@ mod test_replace_nth {
 
 ////////////////////////////////////////////////////////////////////////////////
 
-/// Extension trait for [`std::iter::Iterator`] implementors.
+/// An Iterator that yields the items of `iter`,
+/// then calls `filler` with the index of each item until `min_len` items were yielded.
 ///
-/// [`std::iter::Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
-pub trait IteratorExt: Iterator {
-    /// Collects into an existing collection by extending it.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use core_extensions::iterators::IteratorExt;
-    ///
-    /// let mut list = vec![101, 102];
-    ///
-    /// (0..10)
-    ///     .filter(|&v| v<5 )
-    ///     .map(|v| v*2 )
-    ///     .extending(&mut list);
-    ///
-    /// assert_eq!(list, vec![101, 102, 0, 2, 4, 6, 8]);
-    ///
-    /// ```
-    #[inline(always)]
-    fn extending<C>(self, extend: &mut C)
-    where
-        Self: Sized,
-        C: Extend<Self::Item>,
-    {
-        extend.extend(self);
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::PadUsing;
+///
+/// let list = PadUsing::new(0..2, 5, |i| i * 10).collect::<Vec<_>>();
+///
+/// assert_eq!(list, vec![0, 1, 20, 30, 40]);
+///
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct PadUsing<I, F> {
+    iter: Fuse<I>,
+    index: usize,
+    min_len: usize,
+    filler: F,
+}
+
+impl<I, F> PadUsing<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize) -> I::Item,
+{
+    /// Constructs a `PadUsing`.
+    pub fn new(iter: I, min_len: usize, filler: F) -> Self {
+        Self {
+            iter: iter.fuse(),
+            index: 0,
+            min_len,
+            filler,
+        }
     }
+}
 
-    /// Collects into a pre-allocated collection,returning it by value.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use core_extensions::iterators::IteratorExt;
-    ///
-    /// let list = (0..10)
-    ///     .filter(|&v| v<5 )
-    ///     .map(|v| v*2 )
-    ///     .collect_into(Vec::with_capacity(5));
-    ///
-    /// assert_eq!(list.capacity(), 5);
-    /// assert_eq!(list, vec![0, 2, 4, 6, 8]);
-    ///
-    /// ```
-    /// # Example
-    ///
-    /// Reusing an existing collection.
-    ///
-    /// ```
-    /// use core_extensions::iterators::IteratorExt;
-    ///
-    /// let mut list = Vec::with_capacity(7);
-    /// list.push(100);
-    /// list.push(101);
-    ///
-    /// let list = (0..10)
-    ///     .filter(|&v| v<5 )
-    ///     .map(|v| v*2 )
-    ///     .collect_into(list);
-    ///
-    /// assert_eq!(list.capacity(),7);
-    /// assert_eq!(list, vec![100, 101, 0, 2, 4, 6, 8]);
-    ///
-    /// ```
-    #[inline(always)]
-    fn collect_into<C>(self, mut extend: C) -> C
-    where
-        Self: Sized,
-        C: Extend<Self::Item>,
-    {
-        extend.extend(self);
-        extend
+impl<I, F> Iterator for PadUsing<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let ret = match self.iter.next() {
+            Some(x) => x,
+            None if self.index < self.min_len => (self.filler)(self.index),
+            None => return None,
+        };
+        self.index += 1;
+        Some(ret)
     }
 
-    /// An Iterator that replaces the nth element with another value.
-    ///
-    /// # Example
-    /// ```
-    /// use core_extensions::iterators::IteratorExt;
-    ///
-    /// assert_eq!(
-    ///     (0..=9).replace_nth(5, 1337).collect::<Vec<_>>(),
-    ///     vec![0, 1, 2, 3, 4, 1337, 6, 7, 8, 9]
-    /// );
-    ///
-    /// let list = vec!["hello", "dear", "world"];
-    ///
-    /// assert_eq!(
-    ///     list.into_iter().replace_nth(1, "my").collect::<Vec<_>>(),
-    ///     vec!["hello", "my", "world"]
-    /// );
-    ///
-    ///
-    /// ```
-    #[inline(always)]
-    fn replace_nth(self, nth: usize, with: Self::Item) -> ReplaceNth<Self>
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let padding = self.min_len.saturating_sub(self.index);
+        (
+            cmp::max(low, padding),
+            high.map(|high| cmp::max(high, padding)),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A tuple whose elements are all `T`, usable as a window in [`TupleWindows`].
+///
+/// This is implemented for tuples of 2, 3, and 4 elements.
+///
+/// [`TupleWindows`]: ./struct.TupleWindows.html
+pub trait TupleWindow<T>: Clone {
+    /// The amount of elements in this tuple.
+    const LEN: usize;
+
+    /// Constructs the first window from the first `Self::LEN` items of `iter`,
+    /// returning `None` if there aren't enough items.
+    fn first_window<I>(iter: &mut I) -> Option<Self>
     where
-        Self: Sized,
+        I: Iterator<Item = T>;
+
+    /// Removes the first element of the window, shifts the rest of the elements
+    /// one position towards the start, and puts `elem` at the end.
+    fn push_back(&mut self, elem: T);
+}
+
+impl<T: Clone> TupleWindow<T> for (T, T) {
+    const LEN: usize = 2;
+
+    fn first_window<I>(iter: &mut I) -> Option<Self>
+    where
+        I: Iterator<Item = T>,
     {
-        ReplaceNth::new(self, nth, with)
+        Some((iter.next()?, iter.next()?))
     }
 
-    /// Sums the items of the iterator, into the item's type.
-    ///
-    /// This like the [`Iterator::sum`] method, with better type inference,
-    /// since with the [`Iterator::sum`] method you must specify its return type.
-    ///
-    /// # Example
-    /// 
-    /// ```rust
-    /// use core_extensions::iterators::IteratorExt;
-    ///
-    /// assert_eq!((1..=4).sum_same(), 10);
-    /// 
-    /// let arr = [3, 7, 11, 29];
-    /// assert_eq!(arr.iter().copied().sum_same(), 50);
-    /// 
-    /// ```
-    ///  
-    /// [`Iterator::sum`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.sum 
-    #[inline]
-    fn sum_same(self) -> Self::Item
+    fn push_back(&mut self, elem: T) {
+        self.0 = mem::replace(&mut self.1, elem);
+    }
+}
+
+impl<T: Clone> TupleWindow<T> for (T, T, T) {
+    const LEN: usize = 3;
+
+    fn first_window<I>(iter: &mut I) -> Option<Self>
     where
-        Self: Sized,
-        Self::Item: Sum,
+        I: Iterator<Item = T>,
     {
-        <Self::Item as Sum<Self::Item>>::sum(self)
+        Some((iter.next()?, iter.next()?, iter.next()?))
     }
 
-    /// Multiplies the items of the iterator, into the item's type.
-    ///
-    /// This like the [`Iterator::product`] method, with better type inference,
-    /// since with the [`Iterator::product`] method you must specify its return type.
-    ///
-    /// # Example
-    /// 
-    /// ```rust
-    /// use core_extensions::iterators::IteratorExt;
-    ///
-    /// assert_eq!((1..=4).product_same(), 24);
-    /// 
-    /// let arr = [3, 4, 6];
-    /// assert_eq!(arr.iter().copied().product_same(), 72);
-    /// 
-    /// ```
-    ///  
-    /// [`Iterator::product`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.product
-    #[inline]
-    fn product_same(self) -> Self::Item
+    fn push_back(&mut self, elem: T) {
+        self.0 = mem::replace(&mut self.1, mem::replace(&mut self.2, elem));
+    }
+}
+
+impl<T: Clone> TupleWindow<T> for (T, T, T, T) {
+    const LEN: usize = 4;
+
+    fn first_window<I>(iter: &mut I) -> Option<Self>
     where
-        Self: Sized,
-        Self::Item: Product,
+        I: Iterator<Item = T>,
     {
-        <Self::Item as Product<Self::Item>>::product(self)
+        Some((iter.next()?, iter.next()?, iter.next()?, iter.next()?))
+    }
+
+    fn push_back(&mut self, elem: T) {
+        self.0 = mem::replace(
+            &mut self.1,
+            mem::replace(&mut self.2, mem::replace(&mut self.3, elem)),
+        );
+    }
+}
+
+/// An Iterator over overlapping windows of the items of `iter`, as tuples.
+///
+/// Every item is cloned into each window that contains it.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::TupleWindows;
+///
+/// let list = TupleWindows::<_, (_, _, _)>::new(1..=5).collect::<Vec<_>>();
+///
+/// assert_eq!(list, vec![(1, 2, 3), (2, 3, 4), (3, 4, 5)]);
+///
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct TupleWindows<I, T> {
+    iter: I,
+    last: Option<T>,
+}
+
+impl<I, T> TupleWindows<I, T>
+where
+    I: Iterator,
+    T: TupleWindow<I::Item>,
+{
+    /// Constructs a `TupleWindows`.
+    pub fn new(iter: I) -> Self {
+        Self { iter, last: None }
+    }
+}
+
+impl<I, T> Iterator for TupleWindows<I, T>
+where
+    I: Iterator,
+    T: TupleWindow<I::Item>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self.last {
+            Some(ref mut last) => {
+                last.push_back(self.iter.next()?);
+                Some(last.clone())
+            }
+            None => {
+                let first = T::first_window(&mut self.iter)?;
+                self.last = Some(first.clone());
+                Some(first)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        match self.last {
+            Some(_) => (low, high),
+            None => {
+                let sub = T::LEN - 1;
+                (
+                    low.saturating_sub(sub),
+                    high.map(|high| high.saturating_sub(sub)),
+                )
+            }
+        }
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+
+/// An Iterator over the `Ok` values of an iterator of `Result`s,
+/// which stops at the first `Err`, storing it.
+///
+/// This is constructed by the [`IteratorExt::process_results`] method.
+///
+/// [`IteratorExt::process_results`]: ./trait.IteratorExt.html#method.process_results
+#[derive(Debug)]
+pub struct ProcessResults<'a, I, E: 'a> {
+    iter: I,
+    error: &'a mut Result<(), E>,
+}
+
+impl<'a, I, T, E> Iterator for ProcessResults<'a, I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.error.is_err() {
+            return None;
+        }
+        match self.iter.next()? {
+            Ok(x) => Some(x),
+            Err(e) => {
+                *self.error = Err(e);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_err() {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An Iterator that yields the running totals of the items of `iter`.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::CumulativeSum;
+///
+/// let list = CumulativeSum::new(vec![1, 2, 3, 4].into_iter()).collect::<Vec<_>>();
+///
+/// assert_eq!(list, vec![1, 3, 6, 10]);
+///
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct CumulativeSum<I: Iterator> {
+    iter: I,
+    acc: Option<I::Item>,
+}
+
+impl<I> CumulativeSum<I>
+where
+    I: Iterator,
+    I::Item: Add<Output = I::Item> + Clone,
+{
+    /// Constructs a `CumulativeSum`.
+    pub fn new(iter: I) -> Self {
+        Self { iter, acc: None }
+    }
+}
+
+impl<I> Iterator for CumulativeSum<I>
+where
+    I: Iterator,
+    I::Item: Add<Output = I::Item> + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let elem = self.iter.next()?;
+        let acc = match self.acc.take() {
+            Some(acc) => acc + elem,
+            None => elem,
+        };
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An Iterator that combines each item of `iter` with the previously yielded value,
+/// using `op`.
+///
+/// The first item is yielded unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::Cumulative;
+///
+/// let list = Cumulative::new(vec![3, 1, 4, 1, 5].into_iter(), std::cmp::max)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(list, vec![3, 3, 4, 4, 5]);
+///
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Cumulative<I: Iterator, F> {
+    iter: I,
+    acc: Option<I::Item>,
+    op: F,
+}
+
+impl<I, F> Cumulative<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(I::Item, I::Item) -> I::Item,
+{
+    /// Constructs a `Cumulative`.
+    pub fn new(iter: I, op: F) -> Self {
+        Self {
+            iter,
+            acc: None,
+            op,
+        }
+    }
+}
+
+impl<I, F> Iterator for Cumulative<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(I::Item, I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let elem = self.iter.next()?;
+        let acc = match self.acc.take() {
+            Some(acc) => (self.op)(acc, elem),
+            None => elem,
+        };
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An Iterator that alternates between the items of `a` and `b`,
+/// continuing with the remaining items of the longer one once the other is exhausted.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::Interleave;
+///
+/// let list = Interleave::new(vec![1, 3, 5, 7].into_iter(), vec![2, 4]).collect::<Vec<_>>();
+///
+/// assert_eq!(list, vec![1, 2, 3, 4, 5, 7]);
+///
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Interleave<I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+    b_next: bool,
+}
+
+impl<I, J> Interleave<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    /// Constructs an `Interleave`.
+    pub fn new<JI>(a: I, b: JI) -> Self
+    where
+        JI: IntoIterator<IntoIter = J, Item = I::Item>,
+    {
+        Self {
+            a: a.fuse(),
+            b: b.into_iter().fuse(),
+            b_next: false,
+        }
+    }
+}
+
+impl<I, J> Iterator for Interleave<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.b_next = !self.b_next;
+        if self.b_next {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_low, a_high) = self.a.size_hint();
+        let (b_low, b_high) = self.b.size_hint();
+        let high = match (a_high, b_high) {
+            (Some(a_high), Some(b_high)) => a_high.checked_add(b_high),
+            _ => None,
+        };
+        (a_low.saturating_add(b_low), high)
+    }
+}
+
+/// An Iterator that alternates between the items of `a` and `b`,
+/// stopping once the iterator whose turn it is has no more items.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::InterleaveShortest;
+///
+/// let list = InterleaveShortest::new(vec![1, 3, 5, 7].into_iter(), vec![2, 4])
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(list, vec![1, 2, 3, 4, 5]);
+///
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct InterleaveShortest<I, J> {
+    a: I,
+    b: J,
+    b_next: bool,
+    done: bool,
+}
+
+impl<I, J> InterleaveShortest<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    /// Constructs an `InterleaveShortest`.
+    pub fn new<JI>(a: I, b: JI) -> Self
+    where
+        JI: IntoIterator<IntoIter = J, Item = I::Item>,
+    {
+        Self {
+            a,
+            b: b.into_iter(),
+            b_next: false,
+            done: false,
+        }
+    }
+}
+
+impl<I, J> Iterator for InterleaveShortest<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let ret = if self.b_next {
+            self.b.next()
+        } else {
+            self.a.next()
+        };
+        self.b_next = !self.b_next;
+        self.done = ret.is_none();
+        ret
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        // The amount of items yielded when the iterator whose turn it is has `first` items,
+        // and the other one has `second` items.
+        fn len(first: usize, second: usize) -> usize {
+            if first <= second {
+                first.saturating_mul(2)
+            } else {
+                second.saturating_mul(2).saturating_add(1)
+            }
+        }
+
+        let ((f_low, f_high), (s_low, s_high)) = if self.b_next {
+            (self.b.size_hint(), self.a.size_hint())
+        } else {
+            (self.a.size_hint(), self.b.size_hint())
+        };
+
+        let low = len(f_low, s_low);
+        let high = match (f_high, s_high) {
+            (Some(f_high), Some(s_high)) => Some(len(f_high, s_high)),
+            (Some(f_high), None) => Some(f_high.saturating_mul(2)),
+            (None, Some(s_high)) => Some(s_high.saturating_mul(2).saturating_add(1)),
+            (None, None) => None,
+        };
+        (low, high)
+    }
+}
+
+#[cfg(test)]
+mod test_interleave {
+    use super::*;
+
+    #[test]
+    fn size_hints() {
+        for a_len in 0..6 {
+            for b_len in 0..6 {
+                let mut iter = Interleave::new(0..a_len, 10..10 + b_len);
+                loop {
+                    let len = iter.clone().count();
+                    assert_eq!(iter.size_hint(), (len, Some(len)));
+                    if iter.next().is_none() {
+                        break;
+                    }
+                }
+
+                let mut iter = InterleaveShortest::new(0..a_len, 10..10 + b_len);
+                loop {
+                    let len = iter.clone().count();
+                    assert_eq!(iter.size_hint(), (len, Some(len)));
+                    if iter.next().is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An Iterator that merges the items of two sorted iterators, 
+/// yielding all of them in sorted order.
+///
+/// If both iterators have equal items, the ones from `a` are yielded first.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::MergeSorted;
+///
+/// let list = MergeSorted::new(vec![1, 4, 5].into_iter(), vec![2, 3, 6]).collect::<Vec<_>>();
+///
+/// assert_eq!(list, vec![1, 2, 3, 4, 5, 6]);
+///
+/// ```
+///
+pub struct MergeSorted<I: Iterator, J: Iterator> {
+    a: Peekable<I>,
+    b: Peekable<J>,
+}
+
+impl<I, J> Clone for MergeSorted<I, J>
+where
+    I: Iterator + Clone,
+    J: Iterator + Clone,
+    I::Item: Clone,
+    J::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+}
+
+impl<I, J> fmt::Debug for MergeSorted<I, J>
+where
+    I: Iterator + fmt::Debug,
+    J: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    J::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MergeSorted")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<I, J> MergeSorted<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    I::Item: Ord,
+{
+    /// Constructs a `MergeSorted`.
+    pub fn new<JI>(a: I, b: JI) -> Self
+    where
+        JI: IntoIterator<IntoIter = J, Item = I::Item>,
+    {
+        Self {
+            a: a.peekable(),
+            b: b.into_iter().peekable(),
+        }
+    }
+}
+
+impl<I, J> Iterator for MergeSorted<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        merge_sorted_next(&mut self.a, &mut self.b, Ord::cmp)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        merge_sorted_size_hint(&self.a, &self.b)
+    }
+}
+
+/// An Iterator that merges the items of two iterators sorted by the `cmp` comparator, 
+/// yielding all of them in the order determined by `cmp`.
+///
+/// If `cmp` considers items from both iterators equal, the ones from `a` are yielded first.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::MergeSortedBy;
+///
+/// let list = MergeSortedBy::new(vec![5, 4, 1].into_iter(), vec![6, 3, 2], |l, r| r.cmp(l))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(list, vec![6, 5, 4, 3, 2, 1]);
+///
+/// ```
+///
+pub struct MergeSortedBy<I: Iterator, J: Iterator, F> {
+    a: Peekable<I>,
+    b: Peekable<J>,
+    cmp: F,
+}
+
+impl<I, J, F> Clone for MergeSortedBy<I, J, F>
+where
+    I: Iterator + Clone,
+    J: Iterator + Clone,
+    I::Item: Clone,
+    J::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            cmp: self.cmp.clone(),
+        }
+    }
+}
+
+impl<I, J, F> fmt::Debug for MergeSortedBy<I, J, F>
+where
+    I: Iterator + fmt::Debug,
+    J: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    J::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MergeSortedBy")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<I, J, F> MergeSortedBy<I, J, F>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    /// Constructs a `MergeSortedBy`.
+    pub fn new<JI>(a: I, b: JI, cmp: F) -> Self
+    where
+        JI: IntoIterator<IntoIter = J, Item = I::Item>,
+    {
+        Self {
+            a: a.peekable(),
+            b: b.into_iter().peekable(),
+            cmp,
+        }
+    }
+}
+
+impl<I, J, F> Iterator for MergeSortedBy<I, J, F>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        merge_sorted_next(&mut self.a, &mut self.b, &mut self.cmp)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        merge_sorted_size_hint(&self.a, &self.b)
+    }
+}
+
+fn merge_sorted_next<I, J, F>(a: &mut Peekable<I>, b: &mut Peekable<J>, mut cmp: F) -> Option<I::Item>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    let take_a = match (a.peek(), b.peek()) {
+        (Some(x), Some(y)) => cmp(x, y) != Ordering::Greater,
+        (Some(_), None) => true,
+        (None, _) => false,
+    };
+    if take_a {
+        a.next()
+    } else {
+        b.next()
+    }
+}
+
+fn merge_sorted_size_hint<I, J>(a: &Peekable<I>, b: &Peekable<J>) -> (usize, Option<usize>)
+where
+    I: Iterator,
+    J: Iterator,
+{
+    let (a_low, a_high) = a.size_hint();
+    let (b_low, b_high) = b.size_hint();
+    let high = match (a_high, b_high) {
+        (Some(a_high), Some(b_high)) => a_high.checked_add(b_high),
+        _ => None,
+    };
+    (a_low.saturating_add(b_low), high)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An Iterator like [`std::iter::Scan`], 
+/// which allows getting the state back with the [`into_state`](#method.into_state) method.
+///
+/// Like with `Scan`, this stops yielding items once `f` returns `None`.
+///
+/// [`std::iter::Scan`]: https://doc.rust-lang.org/std/iter/struct.Scan.html
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::ScanState;
+///
+/// let mut iter = ScanState::new(vec![3, 5, 8].into_iter(), 0, |acc: &mut u32, x: u32| {
+///     *acc += x;
+///     Some(*acc * 10)
+/// });
+///
+/// assert_eq!(iter.by_ref().collect::<Vec<_>>(), vec![30, 80, 160]);
+/// assert_eq!(iter.into_state(), 16);
+///
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct ScanState<I, St, F> {
+    iter: I,
+    state: St,
+    f: F,
+}
+
+impl<I, St, F> ScanState<I, St, F> {
+    /// Constructs a `ScanState`.
+    pub fn new(iter: I, state: St, f: F) -> Self {
+        Self { iter, state, f }
+    }
+
+    /// Gets a reference to the current state.
+    pub fn state(&self) -> &St {
+        &self.state
+    }
+
+    /// Gets a mutable reference to the current state.
+    pub fn state_mut(&mut self) -> &mut St {
+        &mut self.state
+    }
+
+    /// Unwraps this into the current state.
+    pub fn into_state(self) -> St {
+        self.state
+    }
+}
+
+impl<I, St, F, B> Iterator for ScanState<I, St, F>
+where
+    I: Iterator,
+    F: FnMut(&mut St, I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<B> {
+        let item = self.iter.next()?;
+        (self.f)(&mut self.state, item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An Iterator that yields each run of equal consecutive items of `iter`,
+/// as the first item of the run along with the length of the run.
+///
+/// [`rle_decode`] does the inverse of this iterator.
+///
+/// [`rle_decode`]: ./fn.rle_decode.html
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::RleEncode;
+///
+/// let list = RleEncode::new("aaabcc".chars()).collect::<Vec<_>>();
+///
+/// assert_eq!(list, vec![('a', 3), ('b', 1), ('c', 2)]);
+///
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct RleEncode<I: Iterator> {
+    iter: I,
+    next: Option<I::Item>,
+}
+
+impl<I> RleEncode<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    /// Constructs an `RleEncode`.
+    pub fn new(iter: I) -> Self {
+        Self { iter, next: None }
+    }
+}
+
+impl<I> Iterator for RleEncode<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<(I::Item, usize)> {
+        let first = match self.next.take() {
+            Some(x) => x,
+            None => self.iter.next()?,
+        };
+        let mut count = 1;
+        for elem in &mut self.iter {
+            if elem == first {
+                count += 1;
+            } else {
+                self.next = Some(elem);
+                break;
+            }
+        }
+        Some((first, count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.next.is_some() as usize;
+        let (low, high) = self.iter.size_hint();
+        let low = if pending == 1 || low != 0 { 1 } else { 0 };
+        (low, high.and_then(|x| x.checked_add(pending)))
+    }
+}
+
+/// Expands `(item, count)` pairs into `count` copies of `item`,
+/// the inverse of [`IteratorExt::rle_encode`].
+///
+/// Pairs with a count of `0` yield nothing.
+///
+/// [`IteratorExt::rle_encode`]: ./trait.IteratorExt.html#method.rle_encode
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::iterators::{IteratorExt, rle_decode};
+///
+/// let decoded = rle_decode(vec![('a', 3), ('b', 1), ('c', 0), ('d', 2)]).collect::<String>();
+/// assert_eq!(decoded, "aaabdd");
+///
+/// let list = [5, 5, 8, 8, 8, 5];
+/// let round_trip = rle_decode(list.iter().rle_encode()).cloned().collect::<Vec<_>>();
+/// assert_eq!(round_trip, list);
+///
+/// ```
+pub fn rle_decode<I, T>(iter: I) -> RleDecode<I::IntoIter, T>
+where
+    I: IntoIterator<Item = (T, usize)>,
+    T: Clone,
+{
+    RleDecode::new(iter.into_iter())
+}
+
+/// An Iterator that expands `(item, count)` pairs into `count` copies of `item`.
+///
+/// This is constructed with the [`rle_decode`] function, which has examples.
+///
+/// [`rle_decode`]: ./fn.rle_decode.html
+#[derive(Debug, Clone)]
+pub struct RleDecode<I, T> {
+    iter: I,
+    current: Option<(T, usize)>,
+}
+
+impl<I, T> RleDecode<I, T>
+where
+    I: Iterator<Item = (T, usize)>,
+    T: Clone,
+{
+    /// Constructs an `RleDecode`.
+    pub fn new(iter: I) -> Self {
+        Self { iter, current: None }
+    }
+}
+
+impl<I, T> Iterator for RleDecode<I, T>
+where
+    I: Iterator<Item = (T, usize)>,
+    T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            match self.current.take() {
+                Some((elem, 1)) => return Some(elem),
+                Some((elem, count)) if count != 0 => {
+                    self.current = Some((elem.clone(), count - 1));
+                    return Some(elem);
+                }
+                _ => self.current = Some(self.iter.next()?),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let low = self.current.as_ref().map_or(0, |x| x.1);
+        match self.iter.size_hint() {
+            (_, Some(0)) => (low, Some(low)),
+            _ => (low, None),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An Iterator that yields the items of `iter` that occur more than once,
+/// each one the second time that it occurs.
+///
+/// This is constructed with the [`IteratorExt::duplicates`] method, which has examples.
+///
+/// [`IteratorExt::duplicates`]: ./trait.IteratorExt.html#method.duplicates
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+#[derive(Debug, Clone)]
+pub struct Duplicates<I: Iterator> {
+    iter: I,
+    // Whether each item was already yielded
+    seen: HashMap<I::Item, bool>,
+}
+
+#[cfg(feature = "std")]
+impl<I> Duplicates<I>
+where
+    I: Iterator,
+    I::Item: Hash + Eq + Clone,
+{
+    /// Constructs a `Duplicates`.
+    pub fn new(iter: I) -> Self {
+        Self { iter, seen: HashMap::new() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I> Iterator for Duplicates<I>
+where
+    I: Iterator,
+    I::Item: Hash + Eq + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        for item in &mut self.iter {
+            match self.seen.get_mut(&item) {
+                Some(yielded) => if !*yielded {
+                    *yielded = true;
+                    return Some(item);
+                },
+                None => {
+                    self.seen.insert(item.clone(), false);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An Iterator that yields the first occurrence of every item in `iter`.
+///
+/// This is constructed with the [`IteratorExt::unique`] method, which has examples.
+///
+/// [`IteratorExt::unique`]: ./trait.IteratorExt.html#method.unique
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+#[derive(Debug, Clone)]
+pub struct Unique<I: Iterator> {
+    iter: I,
+    seen: HashSet<I::Item>,
+}
+
+#[cfg(feature = "std")]
+impl<I> Unique<I>
+where
+    I: Iterator,
+    I::Item: Hash + Eq + Clone,
+{
+    /// Constructs a `Unique`.
+    pub fn new(iter: I) -> Self {
+        Self { iter, seen: HashSet::new() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I> Iterator for Unique<I>
+where
+    I: Iterator,
+    I::Item: Hash + Eq + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let seen = &mut self.seen;
+        self.iter.find(|item| seen.insert(item.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let low = if low != 0 && self.seen.is_empty() { 1 } else { 0 };
+        (low, high)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test_duplicates {
+    use super::*;
+
+    use alloc::vec::Vec;
+
+    #[test]
+    fn duplicates() {
+        let dups = |list: &[u32]| list.iter().cloned().duplicates().collect::<Vec<_>>();
+
+        assert_eq!(dups(&[]), Vec::<u32>::new());
+        assert_eq!(dups(&[1, 2, 3]), Vec::<u32>::new());
+        assert_eq!(dups(&[1, 2, 1, 3, 2]), [1, 2]);
+        assert_eq!(dups(&[2, 1, 1, 2, 2, 1, 1]), [1, 2]);
+        assert_eq!(dups(&[5, 5, 5, 5]), [5]);
+        assert_eq!(dups(&[3, 8, 5, 8, 3, 3]), [8, 3]);
+    }
+
+    #[test]
+    fn unique() {
+        let uniq = |list: &[u32]| list.iter().cloned().unique().collect::<Vec<_>>();
+
+        assert_eq!(uniq(&[]), Vec::<u32>::new());
+        assert_eq!(uniq(&[1, 2, 3]), [1, 2, 3]);
+        assert_eq!(uniq(&[1, 2, 1, 3, 2]), [1, 2, 3]);
+        assert_eq!(uniq(&[5, 5, 5, 5]), [5]);
+        assert_eq!(uniq(&[3, 8, 5, 8, 3, 3, 13]), [3, 8, 5, 13]);
+
+        let mut iter = [3, 3].iter().unique();
+        assert_eq!(iter.size_hint(), (1, Some(2)));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.size_hint(), (0, Some(1)));
+        assert_eq!(iter.next(), None);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The error returned by [`IteratorExt::exactly_one`] and [`IteratorExt::at_most_one`]
+/// when the iterator doesn't have the expected amount of items.
+///
+/// [`IteratorExt::exactly_one`]: ./trait.IteratorExt.html#method.exactly_one
+/// [`IteratorExt::at_most_one`]: ./trait.IteratorExt.html#method.at_most_one
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ItemCountError {
+    /// The iterator had no items.
+    Zero,
+    /// The iterator had more than one item.
+    Multiple,
+}
+
+impl fmt::Display for ItemCountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ItemCountError::Zero => "expected exactly one item, found none",
+            ItemCountError::Multiple => "expected at most one item, found multiple",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std_::error::Error for ItemCountError {}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Extension trait for [`std::iter::Iterator`] implementors.
+///
+/// [`std::iter::Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+pub trait IteratorExt: Iterator {
+    /// Collects into an existing collection by extending it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let mut list = vec![101, 102];
+    ///
+    /// (0..10)
+    ///     .filter(|&v| v<5 )
+    ///     .map(|v| v*2 )
+    ///     .extending(&mut list);
+    ///
+    /// assert_eq!(list, vec![101, 102, 0, 2, 4, 6, 8]);
+    ///
+    /// ```
+    #[inline(always)]
+    fn extending<C>(self, extend: &mut C)
+    where
+        Self: Sized,
+        C: Extend<Self::Item>,
+    {
+        extend.extend(self);
+    }
+
+    /// Collects into a pre-allocated collection,returning it by value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let list = (0..10)
+    ///     .filter(|&v| v<5 )
+    ///     .map(|v| v*2 )
+    ///     .collect_into(Vec::with_capacity(5));
+    ///
+    /// assert_eq!(list.capacity(), 5);
+    /// assert_eq!(list, vec![0, 2, 4, 6, 8]);
+    ///
+    /// ```
+    /// # Example
+    ///
+    /// Reusing an existing collection.
+    ///
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let mut list = Vec::with_capacity(7);
+    /// list.push(100);
+    /// list.push(101);
+    ///
+    /// let list = (0..10)
+    ///     .filter(|&v| v<5 )
+    ///     .map(|v| v*2 )
+    ///     .collect_into(list);
+    ///
+    /// assert_eq!(list.capacity(),7);
+    /// assert_eq!(list, vec![100, 101, 0, 2, 4, 6, 8]);
+    ///
+    /// ```
+    #[inline(always)]
+    fn collect_into<C>(self, mut extend: C) -> C
+    where
+        Self: Sized,
+        C: Extend<Self::Item>,
+    {
+        extend.extend(self);
+        extend
+    }
+
+    /// An Iterator that replaces the nth element with another value.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(
+    ///     (0..=9).replace_nth(5, 1337).collect::<Vec<_>>(),
+    ///     vec![0, 1, 2, 3, 4, 1337, 6, 7, 8, 9]
+    /// );
+    ///
+    /// let list = vec!["hello", "dear", "world"];
+    ///
+    /// assert_eq!(
+    ///     list.into_iter().replace_nth(1, "my").collect::<Vec<_>>(),
+    ///     vec!["hello", "my", "world"]
+    /// );
+    ///
+    ///
+    /// ```
+    #[inline(always)]
+    fn replace_nth(self, nth: usize, with: Self::Item) -> ReplaceNth<Self>
+    where
+        Self: Sized,
+    {
+        ReplaceNth::new(self, nth, with)
+    }
+
+    /// Yields the items of this iterator,
+    /// then calls `filler` with the index of each item until `min_len` items were yielded.
+    ///
+    /// If this iterator has at least `min_len` items, `filler` is never called.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(
+    ///     (1..=2).pad_using(5, |i| i * 100).collect::<Vec<_>>(),
+    ///     vec![1, 2, 200, 300, 400]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vec!["foo", "bar"].into_iter().pad_using(1, |_| unreachable!()).collect::<Vec<_>>(),
+    ///     vec!["foo", "bar"]
+    /// );
+    ///
+    /// ```
+    #[inline(always)]
+    fn pad_using<F>(self, min_len: usize, filler: F) -> PadUsing<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize) -> Self::Item,
+    {
+        PadUsing::new(self, min_len, filler)
+    }
+
+    /// Returns an iterator over overlapping windows of the items of this iterator,
+    /// as tuples of 2, 3, or 4 elements.
+    ///
+    /// This requires `Self::Item: Clone`,
+    /// because every item is cloned into each window that contains it.
+    ///
+    /// If this iterator has fewer items than the tuple, the returned iterator is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(
+    ///     vec![1, 2, 3, 4].into_iter().tuple_windows().collect::<Vec<(_, _)>>(),
+    ///     vec![(1, 2), (2, 3), (3, 4)]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "hello".chars().tuple_windows().collect::<Vec<(_, _, _)>>(),
+    ///     vec![('h', 'e', 'l'), ('e', 'l', 'l'), ('l', 'l', 'o')]
+    /// );
+    ///
+    /// assert_eq!((0..3).tuple_windows::<(_, _, _, _)>().next(), None);
+    ///
+    /// ```
+    #[inline(always)]
+    fn tuple_windows<T>(self) -> TupleWindows<Self, T>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        T: TupleWindow<Self::Item>,
+    {
+        TupleWindows::new(self)
+    }
+
+    /// Calls `f` with an iterator over the `Ok` values of this iterator of `Result`s,
+    /// returning the first `Err` if there was one.
+    ///
+    /// The iterator passed to `f` stops at the first `Err`,
+    /// which is then returned instead of the value that `f` returned.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let ok: Vec<Result<i32, String>> = vec![Ok(3), Ok(5), Ok(8)];
+    /// assert_eq!(ok.into_iter().process_results(|iter| iter.sum::<i32>()), Ok(16));
+    ///
+    /// let mut visited = Vec::new();
+    ///
+    /// let err: Vec<Result<i32, String>> = 
+    ///     vec![Ok(3), Err("hello".to_string()), Ok(5), Err("world".to_string())];
+    ///
+    /// let res = err.into_iter().process_results(|iter| {
+    ///     iter.inspect(|x| visited.push(*x)).sum::<i32>()
+    /// });
+    ///
+    /// assert_eq!(res, Err("hello".to_string()));
+    /// assert_eq!(visited, vec![3]);
+    ///
+    /// ```
+    fn process_results<T, E, R, F>(self, f: F) -> Result<R, E>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: for<'a> FnOnce(ProcessResults<'a, Self, E>) -> R,
+    {
+        let mut error = Ok(());
+        let ret = f(ProcessResults {
+            iter: self,
+            error: &mut error,
+        });
+        error.map(|_| ret)
+    }
+
+    /// Returns an iterator over the running totals of the items of this iterator.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(
+    ///     vec![1, 2, 3].into_iter().cumulative_sum().collect::<Vec<_>>(),
+    ///     vec![1, 3, 6]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vec![0.5, 0.25, 0.25].into_iter().cumulative_sum().collect::<Vec<_>>(),
+    ///     vec![0.5, 0.75, 1.0]
+    /// );
+    ///
+    /// assert_eq!((0..0).cumulative_sum().next(), None);
+    ///
+    /// ```
+    #[inline(always)]
+    fn cumulative_sum(self) -> CumulativeSum<Self>
+    where
+        Self: Sized,
+        Self::Item: Add<Output = Self::Item> + Clone,
+    {
+        CumulativeSum::new(self)
+    }
+
+    /// Returns an iterator that combines each item with the previously yielded value,
+    /// using `op`.
+    ///
+    /// The first item is yielded unchanged,
+    /// so `cumulative(|l, r| l + r)` is equivalent to [`cumulative_sum`].
+    ///
+    /// [`cumulative_sum`]: #method.cumulative_sum
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(
+    ///     (1..=5).cumulative(|l, r| l * r).collect::<Vec<_>>(),
+    ///     vec![1, 2, 6, 24, 120]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vec![2, 5, 3, 8, 1].into_iter().cumulative(std::cmp::max).collect::<Vec<_>>(),
+    ///     vec![2, 5, 5, 8, 8]
+    /// );
+    ///
+    /// ```
+    #[inline(always)]
+    fn cumulative<F>(self, op: F) -> Cumulative<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        Cumulative::new(self, op)
+    }
+
+    /// Returns an iterator that alternates between the items of this iterator and `other`,
+    /// continuing with the remaining items of the longer one once the other is exhausted.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(
+    ///     vec![1, 3, 5].into_iter().interleave(vec![2, 4]).collect::<Vec<_>>(),
+    ///     vec![1, 2, 3, 4, 5]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "ab".chars().interleave("123".chars()).collect::<String>(),
+    ///     "a1b23"
+    /// );
+    ///
+    /// ```
+    #[inline(always)]
+    fn interleave<I>(self, other: I) -> Interleave<Self, I::IntoIter>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Self::Item>,
+    {
+        Interleave::new(self, other)
+    }
+
+    /// Returns an iterator that alternates between the items of this iterator and `other`,
+    /// stopping once the iterator whose turn it is has no more items.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(
+    ///     vec![1, 3, 5].into_iter().interleave_shortest(vec![2, 4]).collect::<Vec<_>>(),
+    ///     vec![1, 2, 3, 4, 5]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "ab".chars().interleave_shortest("123".chars()).collect::<String>(),
+    ///     "a1b2"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "abc".chars().interleave_shortest("1".chars()).collect::<String>(),
+    ///     "a1b"
+    /// );
+    ///
+    /// ```
+    #[inline(always)]
+    fn interleave_shortest<I>(self, other: I) -> InterleaveShortest<Self, I::IntoIter>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Self::Item>,
+    {
+        InterleaveShortest::new(self, other)
+    }
+
+    /// Merges this iterator with `other`, yielding all of their items in sorted order,
+    /// assuming that both iterators are sorted.
+    ///
+    /// If both iterators have equal items, the ones from this iterator are yielded first.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(
+    ///     vec![1, 3, 5].into_iter().merge_sorted(vec![2, 4, 6]).collect::<Vec<_>>(),
+    ///     vec![1, 2, 3, 4, 5, 6]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vec![2, 8].into_iter().merge_sorted(vec![1, 3, 5, 13, 21]).collect::<Vec<_>>(),
+    ///     vec![1, 2, 3, 5, 8, 13, 21]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     (0..0).merge_sorted(vec![3, 5]).collect::<Vec<_>>(),
+    ///     vec![3, 5]
+    /// );
+    ///
+    /// ```
+    #[inline(always)]
+    fn merge_sorted<I>(self, other: I) -> MergeSorted<Self, I::IntoIter>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Self::Item>,
+        Self::Item: Ord,
+    {
+        MergeSorted::new(self, other)
+    }
+
+    /// Merges this iterator with `other`, yielding all of their items in sorted order,
+    /// assuming that both iterators are sorted by the `cmp` comparator.
+    ///
+    /// If `cmp` considers items from both iterators equal,
+    /// the ones from this iterator are yielded first.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let by_len = vec!["a", "ccc", "dddd"].into_iter()
+    ///     .merge_sorted_by(vec!["b", "ee", "fff"], |l, r| l.len().cmp(&r.len()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(by_len, vec!["a", "b", "ee", "ccc", "fff", "dddd"]);
+    ///
+    /// ```
+    #[inline(always)]
+    fn merge_sorted_by<I, F>(self, other: I, cmp: F) -> MergeSortedBy<Self, I::IntoIter, F>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Self::Item>,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        MergeSortedBy::new(self, other, cmp)
+    }
+
+    /// Returns an iterator like the one returned by [`Iterator::scan`],
+    /// which allows getting the state back after iterating.
+    ///
+    /// `f` is passed a mutable reference to the state along with each item,
+    /// the iterator stops once `f` returns `None`.
+    ///
+    /// [`Iterator::scan`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.scan
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// // Parses a number one digit at a time, yielding the digits.
+    /// let mut iter = "1234 rest".chars().scan_state(0u32, |acc, c| {
+    ///     let digit = c.to_digit(10)?;
+    ///     *acc = *acc * 10 + digit;
+    ///     Some(digit)
+    /// });
+    ///
+    /// assert_eq!(iter.by_ref().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// assert_eq!(iter.into_state(), 1234);
+    ///
+    /// ```
+    #[inline(always)]
+    fn scan_state<St, B, F>(self, init: St, f: F) -> ScanState<Self, St, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, Self::Item) -> Option<B>,
+    {
+        ScanState::new(self, init, f)
+    }
+
+    /// Returns an iterator over the runs of equal consecutive items,
+    /// yielding the first item of each run along with the length of the run.
+    ///
+    /// The [`rle_decode`] function does the inverse of this.
+    ///
+    /// [`rle_decode`]: ./fn.rle_decode.html
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::{IteratorExt, rle_decode};
+    ///
+    /// let list = vec!['a', 'a', 'b', 'c', 'c', 'c'];
+    ///
+    /// let encoded = list.iter().cloned().rle_encode().collect::<Vec<_>>();
+    /// assert_eq!(encoded, vec![('a', 2), ('b', 1), ('c', 3)]);
+    ///
+    /// assert_eq!(rle_decode(encoded).collect::<Vec<_>>(), list);
+    ///
+    /// assert_eq!((0..0).rle_encode().next(), None);
+    ///
+    /// ```
+    #[inline(always)]
+    fn rle_encode(self) -> RleEncode<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        RleEncode::new(self)
+    }
+
+    /// Returns the only item of the iterator,
+    /// erroring if it has zero or multiple items.
+    ///
+    /// This stops iterating after the second item.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::{IteratorExt, ItemCountError};
+    ///
+    /// assert_eq!((0..0).exactly_one(), Err(ItemCountError::Zero));
+    /// assert_eq!((0..1).exactly_one(), Ok(0));
+    /// assert_eq!((0..2).exactly_one(), Err(ItemCountError::Multiple));
+    ///
+    /// let words = ["foo", "bar", "baz"];
+    /// assert_eq!(words.iter().filter(|w| w.starts_with('f')).exactly_one(), Ok(&"foo"));
+    /// assert_eq!(words.iter().filter(|w| w.starts_with('b')).exactly_one(), Err(ItemCountError::Multiple));
+    ///
+    /// ```
+    fn exactly_one(self) -> Result<Self::Item, ItemCountError>
+    where
+        Self: Sized,
+    {
+        match self.at_most_one()? {
+            Some(x) => Ok(x),
+            None => Err(ItemCountError::Zero),
+        }
+    }
+
+    /// Returns the only item of the iterator, or `None` if it's empty,
+    /// erroring with `ItemCountError::Multiple` if it has multiple items.
+    ///
+    /// This stops iterating after the second item.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::iterators::{IteratorExt, ItemCountError};
+    ///
+    /// assert_eq!((0..0).at_most_one(), Ok(None));
+    /// assert_eq!((0..1).at_most_one(), Ok(Some(0)));
+    /// assert_eq!((0..2).at_most_one(), Err(ItemCountError::Multiple));
+    ///
+    /// let mut iter = 0..10;
+    /// assert_eq!(iter.by_ref().at_most_one(), Err(ItemCountError::Multiple));
+    /// assert_eq!(iter.next(), Some(2));
+    ///
+    /// ```
+    fn at_most_one(mut self) -> Result<Option<Self::Item>, ItemCountError>
+    where
+        Self: Sized,
+    {
+        match (self.next(), self.next()) {
+            (Some(_), Some(_)) => Err(ItemCountError::Multiple),
+            (first, _) => Ok(first),
+        }
+    }
+
+    /// Sums the items of the iterator, into the item's type.
+    ///
+    /// This like the [`Iterator::sum`] method, with better type inference,
+    /// since with the [`Iterator::sum`] method you must specify its return type.
+    ///
+    /// # Example
+    /// 
+    /// ```rust
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!((1..=4).sum_same(), 10);
+    /// 
+    /// let arr = [3, 7, 11, 29];
+    /// assert_eq!(arr.iter().copied().sum_same(), 50);
+    /// 
+    /// ```
+    ///  
+    /// [`Iterator::sum`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.sum 
+    #[inline]
+    fn sum_same(self) -> Self::Item
+    where
+        Self: Sized,
+        Self::Item: Sum,
+    {
+        <Self::Item as Sum<Self::Item>>::sum(self)
+    }
+
+    /// Multiplies the items of the iterator, into the item's type.
+    ///
+    /// This like the [`Iterator::product`] method, with better type inference,
+    /// since with the [`Iterator::product`] method you must specify its return type.
+    ///
+    /// # Example
+    /// 
+    /// ```rust
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!((1..=4).product_same(), 24);
+    /// 
+    /// let arr = [3, 4, 6];
+    /// assert_eq!(arr.iter().copied().product_same(), 72);
+    /// 
+    /// ```
+    ///  
+    /// [`Iterator::product`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.product
+    #[inline]
+    fn product_same(self) -> Self::Item
+    where
+        Self: Sized,
+        Self::Item: Product,
+    {
+        <Self::Item as Product<Self::Item>>::product(self)
+    }
+
+    /// Sums the items of the iterator, returning the sum along with the amount of items.
+    ///
+    /// This iterates only once, which is useful for computing averages.
+    ///
+    /// # Example
+    /// 
+    /// ```rust
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4].into_iter().sum_count(), (10, 4));
+    /// 
+    /// let (sum, count) = [2.5, 3.0, 3.5].iter().copied().sum_count();
+    /// assert_eq!(sum / count as f64, 3.0);
+    ///
+    /// assert_eq!((0..0).sum_count(), (0, 0));
+    /// 
+    /// ```
+    #[inline]
+    fn sum_count(self) -> (Self::Item, usize)
+    where
+        Self: Sized,
+        Self::Item: Sum,
+    {
+        let mut count = 0;
+        let sum = <Self::Item as Sum<Self::Item>>::sum(self.inspect(|_| count += 1));
+        (sum, count)
+    }
+
+    /// Computes the arithmetic mean of the items of the iterator,
+    /// returning `None` if the iterator is empty.
+    ///
+    /// The items are converted to `f64` before being summed.
+    ///
+    /// # Example
+    /// 
+    /// ```rust
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// assert_eq!(vec![1u32, 2, 3, 4].into_iter().average(), Some(2.5));
+    /// assert_eq!([-3i8, 3, 6].iter().copied().average(), Some(2.0));
+    /// assert_eq!([0.5f32, 1.5].iter().copied().average(), Some(1.0));
+    /// assert_eq!((0..0u8).average(), None);
+    /// 
+    /// ```
+    #[inline]
+    fn average(self) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: Into<f64>,
+    {
+        match self.map(Into::into).sum_count() {
+            (_, 0) => None,
+            (sum, count) => Some(sum / count as f64),
+        }
+    }
+
+    /// Searches for the first item that satisfies `pred`,
+    /// returning both its index and the item itself.
+    ///
+    /// This combines [`Iterator::position`] and [`Iterator::find`],
+    /// stopping iteration right after the found item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let mut iter = vec!["foo", "bar", "baz", "qux"].into_iter();
+    ///
+    /// assert_eq!(iter.find_position(|s| s.starts_with('b')), Some((1, "bar")));
+    ///
+    /// // the returned index is relative to where the search started
+    /// assert_eq!(iter.find_position(|s| s.starts_with('q')), Some((1, "qux")));
+    ///
+    /// assert_eq!(iter.find_position(|_| true), None);
+    ///
+    /// ```
+    ///
+    /// [`Iterator::position`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.position
+    /// [`Iterator::find`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.find
+    #[inline]
+    fn find_position<P>(&mut self, mut pred: P) -> Option<(usize, Self::Item)>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        for (index, item) in self.enumerate() {
+            if pred(&item) {
+                return Some((index, item));
+            }
+        }
+        None
+    }
+
+    /// Counts how many times each item occurs in the iterator.
+    ///
+    /// The iteration order of the returned `HashMap` is unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let counts = "hello".chars().counts();
+    ///
+    /// assert_eq!(counts.len(), 4);
+    /// assert_eq!(counts[&'h'], 1);
+    /// assert_eq!(counts[&'e'], 1);
+    /// assert_eq!(counts[&'l'], 2);
+    /// assert_eq!(counts[&'o'], 1);
+    /// assert_eq!(counts.get(&'z'), None);
+    ///
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+    #[inline]
+    fn counts(self) -> HashMap<Self::Item, usize>
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq,
+    {
+        self.counts_by(|x| x)
+    }
+
+    /// Counts how many items map to each key returned by `mapper`.
+    ///
+    /// The iteration order of the returned `HashMap` is unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let counts = "Hello World".chars().counts_by(|c| c.to_ascii_lowercase());
+    ///
+    /// assert_eq!(counts[&'h'], 1);
+    /// assert_eq!(counts[&'l'], 3);
+    /// assert_eq!(counts[&'o'], 2);
+    /// assert_eq!(counts[&'w'], 1);
+    ///
+    /// let parities = (0..7).counts_by(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(parities[&true], 4);
+    /// assert_eq!(parities[&false], 3);
+    ///
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+    fn counts_by<K, F>(self, mut mapper: F) -> HashMap<K, usize>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> K,
+        K: Hash + Eq,
+    {
+        let mut map = HashMap::new();
+        for item in self {
+            *map.entry(mapper(item)).or_insert(0) += 1;
+        }
+        map
+    }
+
+    /// Returns an iterator over the items that occur more than once,
+    /// yielding each one the second time that it occurs.
+    ///
+    /// Every duplicated item is only yielded once,
+    /// in the order in which the items were first repeated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let list = [1, 2, 1, 3, 2, 1];
+    /// assert_eq!(list.iter().duplicates().collect::<Vec<_>>(), [&1, &2]);
+    ///
+    /// let dups = "mississippi".chars().duplicates().collect::<String>();
+    /// assert_eq!(dups, "sip");
+    ///
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+    #[inline]
+    fn duplicates(self) -> Duplicates<Self>
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq + Clone,
+    {
+        Duplicates::new(self)
+    }
+
+    /// Returns an iterator over the first occurrence of every item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core_extensions::iterators::IteratorExt;
+    ///
+    /// let list = [1, 2, 1, 3, 2, 1];
+    /// assert_eq!(list.iter().unique().collect::<Vec<_>>(), [&1, &2, &3]);
+    ///
+    /// let uniq = "mississippi".chars().unique().collect::<String>();
+    /// assert_eq!(uniq, "misp");
+    ///
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+    #[inline]
+    fn unique(self) -> Unique<Self>
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq + Clone,
+    {
+        Unique::new(self)
+    }
+
+    #[cfg(feature = "rust_1_51")]
+    iterator_ext_const_generic_methods!{}
+}
+
 impl<I> IteratorExt for I where I: ?Sized + Iterator {}
 
 ////////////////////////////////////////////////////////////////////////////////
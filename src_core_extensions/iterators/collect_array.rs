@@ -0,0 +1,81 @@
+use std_::{
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+
+// An array whose first `len` elements are initialized,
+// dropping only those elements when it's dropped.
+struct PartialArray<T, const N: usize> {
+    array: MaybeUninit<[T; N]>,
+    len: usize,
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        // Safety: the first `len` elements are initialized
+        unsafe {
+            let start = self.array.as_mut_ptr() as *mut T;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(start, self.len));
+        }
+    }
+}
+
+pub(crate) fn collect_array<I, const N: usize>(iter: I) -> Option<[I::Item; N]>
+where
+    I: Iterator,
+{
+    let mut out = PartialArray::<I::Item, N> {
+        array: MaybeUninit::uninit(),
+        len: 0,
+    };
+
+    for elem in iter.take(N) {
+        // Safety: `take(N)` ensures that `out.len < N` here
+        unsafe {
+            (out.array.as_mut_ptr() as *mut I::Item).add(out.len).write(elem);
+        }
+        out.len += 1;
+    }
+
+    if out.len != N {
+        return None;
+    }
+
+    let out = ManuallyDrop::new(out);
+    // Safety: all `N` elements were initialized,
+    // and `out` is never dropped, so they're moved out only once.
+    unsafe { Some(ptr::read(&out.array).assume_init()) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::collect_array;
+
+    use std_::cell::Cell;
+
+    use test_utils::DecOnDrop;
+
+    #[test]
+    fn collect_array_drops() {
+        let count = Cell::new(3);
+
+        {
+            // `collect_array` must not create more than 3 elements
+            let iter = (0..5).map(|_| DecOnDrop::new(&count));
+            let arr: Option<[_; 3]> = collect_array(iter);
+            assert_eq!(count.get(), 3);
+            assert!(arr.is_some());
+        }
+        assert_eq!(count.get(), 0);
+
+        count.set(2);
+        {
+            let iter = (0..2).map(|_| DecOnDrop::new(&count));
+            let arr: Option<[_; 3]> = collect_array(iter);
+            assert!(arr.is_none());
+            assert_eq!(count.get(), 0);
+        }
+    }
+}
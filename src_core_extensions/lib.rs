@@ -172,7 +172,7 @@
 //!
 //! - `"item_parsing"`: 
 //! Enables the `"macro_utils` and `"generics_parsing"` features.
-//! Enables the [`impl_parse_generics`] and [`impl_split`] macros.
+//! Enables the [`impl_parse_generics`], [`impl_split`], and [`parse_const_item`] macros.
 //!
 //! - `"integers"`: Enables the [`integers`] module, with extension traits for integer types.
 //!
@@ -224,6 +224,7 @@
 //!
 //! - "rust_1_46": Makes [`TransparentNewtype`] and [`TypeIdentity`]
 //! associated functions that take `Rc<Self>` or `Arc<Self>` callable as methods.
+//! Makes [`parse_generics`] and [`parse_generics_and_where`] remove duplicate lifetime bounds.
 //!
 //! - "rust_1_51": Enables the "rust_1_46" feature, and impls of traits for all array lengths.
 //! Enables the `"on_drop"` feature because [`RunOnDrop`] is used by the impls for 
@@ -249,7 +250,11 @@
 //! `"track_caller"`:
 //! Enables the "rust_1_46" feature.
 //! Changes `ResultLike` to allow getting the caller location in `ResultLike::into_result_`,
-//! and makes `IsNoneError` store where it was constructed.
+//! and makes `IsNoneError` and `IsPendingError` store where they were constructed.
+//!
+//! `"timed"`:
+//! Enables the `"std"` and `"macro_utils"` features.
+//! Enables the [`timed`] attribute, which prints how long a function took to run.
 //!
 //! `"docsrs"`: Used to document the required features in docs.rs, requires Rust nightly.
 //! Doesn't enable any items itself.
@@ -284,6 +289,7 @@
 //! [`compile_error_stringify`]: ./macro.compile_error_stringify.html
 //! [`parenthesize_args`]: ./macro.parenthesize_args.html
 //! [`macro_attr`]: ./attr.macro_attr.html
+//! [`timed`]: ./measure_time/attr.timed.html
 //! [`parse_generics`]: ./macro.parse_generics.html
 //! [`parse_generics_and_where`]: ./macro.parse_generics_and_where.html
 //! [`split_generics_and_where`]: ./macro.split_generics_and_where.html
@@ -292,6 +298,7 @@
 //!
 //! [`impl_parse_generics`]: ./macro.impl_parse_generics.html
 //! [`impl_split`]: ./macro.impl_split.html
+//! [`parse_const_item`]: ./macro.parse_const_item.html
 //!
 //! [`BoolExt`]: ./trait.BoolExt.html
 //! [`ConstDefault`]: ./trait.ConstDefault.html
@@ -411,7 +418,7 @@ pub mod integers;
 
 #[cfg(feature = "integers")]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "integers")))]
-pub use self::integers::{IntegerExt, ToTime};
+pub use self::integers::{IntegerExt, NonZeroExt, ToTime};
 
 
 #[cfg(feature = "iterators")]
@@ -565,11 +572,16 @@ pub mod __ {
     #[cfg(feature = "marker_type")]
     pub use crate::marker_type::assert_markertype;
 
+    #[cfg(all(feature = "slices", feature = "rust_1_51"))]
+    pub use crate::macros::concat_arrays::ConcatArrays;
+
     #[cfg(feature = "macro_utils")]
     pub use core_extensions_proc_macros::{__priv_rewrap_macro_parameters, count_tts};
 
     #[cfg(feature = "enable_proc_macro_crate")]
-    pub use core_extensions_proc_macros::{__priv_unwrap_bound, __priv_split_generics};
+    pub use core_extensions_proc_macros::{
+        __priv_dedup_lifetime_bounds, __priv_unwrap_bound, __priv_split_generics,
+    };
 
     #[cfg(feature = "item_parsing")]
     pub use core_extensions_proc_macros::__priv_split_impl;
@@ -1,6 +1,10 @@
 //! A variety of macros.
 
 
+#[macro_use]
+#[cfg(all(feature = "slices", feature = "rust_1_51"))]
+pub(crate) mod concat_arrays;
+
 #[macro_use]
 mod const_default;
 
@@ -0,0 +1,146 @@
+use std_::{
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+
+/// Concatenates arrays by value into a single array.
+///
+/// The length of the returned array is inferred from the context,
+/// it's a compile-time error for it to differ from the sum of the lengths of the arguments.
+///
+/// This macro accepts from 1 up to 8 arrays,
+/// all of which must have the same element type.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::concat_arrays;
+///
+/// let array: [u8; 5] = concat_arrays!([1, 2], [3, 4, 5]);
+/// assert_eq!(array, [1, 2, 3, 4, 5]);
+///
+/// const MAGIC: [u8; 4] = *b"HDR0";
+/// let len = 1000u16.to_le_bytes();
+/// let flags = [0b101u8];
+///
+/// let header: [u8; 7] = concat_arrays!(MAGIC, len, flags);
+/// assert_eq!(header, [b'H', b'D', b'R', b'0', 0xE8, 0x03, 0b101]);
+///
+/// // Arrays of non-`Copy` elements are moved into the returned array.
+/// let words: [String; 3] = concat_arrays!(["foo".to_string()], ["bar".into(), "baz".into()]);
+/// assert_eq!(words, ["foo", "bar", "baz"]);
+///
+/// ```
+///
+/// ### Length mismatch
+///
+/// This fails to compile because the returned array is one element shorter than necessary.
+///
+/// ```compile_fail
+/// use core_extensions::concat_arrays;
+///
+/// let array: [u8; 4] = concat_arrays!([1, 2], [3, 4, 5]);
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(all(feature = "slices", feature = "rust_1_51"))))]
+#[macro_export]
+macro_rules! concat_arrays {
+    ($($array:expr),+ $(,)*) => (
+        $crate::__::ConcatArrays::concat_arrays(($($array,)+))
+    )
+}
+
+
+#[doc(hidden)]
+pub trait ConcatArrays: Sized {
+    type Elem;
+
+    const LEN: usize;
+
+    fn concat_arrays<const LEN: usize>(self) -> [Self::Elem; LEN];
+}
+
+struct AssertLen<This, const LEN: usize>(This);
+
+impl<This: ConcatArrays, const LEN: usize> AssertLen<This, LEN> {
+    // Indexing out of bounds causes a compile-time error
+    // when the lengths differ.
+    const OK: () = [()][(This::LEN != LEN) as usize];
+}
+
+macro_rules! impl_concat_arrays {
+    ($( ($($index:tt $len:ident),*) )*) => ($(
+        impl<T, $(const $len: usize,)*> ConcatArrays for ($([T; $len],)*) {
+            type Elem = T;
+
+            const LEN: usize = 0 $(+ $len)*;
+
+            #[allow(unused_assignments)]
+            fn concat_arrays<const LEN: usize>(self) -> [T; LEN] {
+                let () = AssertLen::<Self, LEN>::OK;
+
+                let this = ManuallyDrop::new(self);
+                let mut out = MaybeUninit::<[T; LEN]>::uninit();
+                let mut out_ptr = out.as_mut_ptr() as *mut T;
+
+                // Safety: The lengths of the arrays add up to `LEN`,
+                // and the elements are moved out of `this`, which is never dropped.
+                unsafe {
+                    $(
+                        ptr::copy_nonoverlapping(this.$index.as_ptr(), out_ptr, $len);
+                        out_ptr = out_ptr.add($len);
+                    )*
+                    out.assume_init()
+                }
+            }
+        }
+    )*)
+}
+
+impl_concat_arrays! {
+    (0 A0)
+    (0 A0, 1 A1)
+    (0 A0, 1 A1, 2 A2)
+    (0 A0, 1 A1, 2 A2, 3 A3)
+    (0 A0, 1 A1, 2 A2, 3 A3, 4 A4)
+    (0 A0, 1 A1, 2 A2, 3 A3, 4 A4, 5 A5)
+    (0 A0, 1 A1, 2 A2, 3 A3, 4 A4, 5 A5, 6 A6)
+    (0 A0, 1 A1, 2 A2, 3 A3, 4 A4, 5 A5, 6 A6, 7 A7)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std_::cell::Cell;
+
+    use test_utils::{DecOnDrop, WithVal};
+
+    #[test]
+    fn concat_arities() {
+        let _: [u8; 0] = concat_arrays!([]);
+        let _: [u8; 0] = concat_arrays!([], []);
+        assert_eq!(concat_arrays!([3u8]), [3]);
+        assert_eq!(concat_arrays!([3u8], [], [5, 8]), [3, 5, 8]);
+        assert_eq!(
+            concat_arrays!([0u8], [1], [2, 3], [4], [5], [6, 7], [8], [9]),
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        );
+    }
+
+    #[test]
+    fn concat_drops() {
+        let count = Cell::new(5);
+        let make = |x: u32| WithVal(x, DecOnDrop::new(&count));
+
+        {
+            let arr: [_; 5] = concat_arrays!([make(3), make(5)], [make(8), make(13), make(21)]);
+            assert_eq!(count.get(), 5);
+
+            for (elem, expected) in arr.iter().zip([3, 5, 8, 13, 21].iter()) {
+                assert_eq!(elem.0, *expected);
+            }
+        }
+        assert_eq!(count.get(), 0);
+    }
+}
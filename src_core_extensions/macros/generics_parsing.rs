@@ -391,6 +391,15 @@ macro_rules! __pgaw_parsed_generics {
 /// Transforms generic parameters for use in type definitions,
 /// impl blocks and generic arguments, passing them to a callback macro.
 /// 
+/// With the `"rust_1_46"` feature, duplicate lifetime bounds are removed
+/// (eg: `'a: 'b + 'b` becomes `'a: 'b +`),
+/// otherwise the order of the generic parameters and their bounds is preserved.
+/// 
+/// Without the `"rust_1_46"` feature, duplicate lifetime bounds are kept as written.
+/// Because cargo unifies features, any crate in the dependency graph enabling 
+/// `"rust_1_46"` enables the deduplication for every user of this macro,
+/// so callback macros shouldn't rely on duplicate bounds being either kept or removed.
+/// 
 /// # Examples
 /// 
 /// ### Basic
@@ -488,17 +497,38 @@ macro_rules! __pg_inner {
         ($($impl_params:tt)*)
         ($($impl_args:tt)*)
         ($($phantoms:tt)*)
-        ($lifetime:lifetime $(: $($bound:lifetime $(+)? )*)? , $($rem:tt)*)
+        ($lifetime:lifetime , $($rem:tt)*)
     ) => {
         $crate::__pg_inner!{
             $other
-            ($($struct_params)* $lifetime $(: $($bound + )*)?,)
-            ($($impl_params)* $lifetime $(: $($bound + )*)?,)
+            ($($struct_params)* $lifetime,)
+            ($($impl_params)* $lifetime,)
             ($($impl_args)* $lifetime,)
             ($($phantoms)* &$lifetime (),)
             ($($rem)*)
         }
     };
+    (
+        $other:tt
+        $struct_params:tt
+        $impl_params:tt
+        $impl_args:tt
+        $phantoms:tt
+        ($lifetime:lifetime : $($bound:lifetime $(+)? )* , $($rem:tt)*)
+    ) => {
+        $crate::__pg_lifetime_bounds!{
+            ($($bound)*)
+            (
+                $other
+                $lifetime
+                $struct_params
+                $impl_params
+                $impl_args
+                $phantoms
+            )
+            ($($rem)*)
+        }
+    };
     (
         $other:tt
         ($($struct_params:tt)*  )
@@ -570,6 +600,59 @@ macro_rules! __pg_inner {
 }
 
 
+// Function-like proc macros can only be used outside of item position since Rust 1.45,
+// so duplicate lifetime bounds are only removed with the "rust_1_46" feature.
+#[cfg(feature = "rust_1_46")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pg_lifetime_bounds {
+    (($($bound:tt)*) $params:tt $rem:tt) => {
+        $crate::__::__priv_dedup_lifetime_bounds!{
+            ($($bound)*)
+
+            $crate::__pg_lifetime_param_finish!{$params $rem}
+        }
+    };
+}
+
+#[cfg(not(feature = "rust_1_46"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pg_lifetime_bounds {
+    (($($bound:tt)*) $params:tt $rem:tt) => {
+        $crate::__pg_lifetime_param_finish!{$params $rem ($($bound +)*)}
+    };
+}
+
+// Receives the lifetime bounds with duplicates removed,
+// the order of the remaining bounds is preserved.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pg_lifetime_param_finish {
+    (
+        (
+            $other:tt
+            $lifetime:lifetime
+            ($($struct_params:tt)*)
+            ($($impl_params:tt)*)
+            ($($impl_args:tt)*)
+            ($($phantoms:tt)*)
+        )
+        ($($rem:tt)*)
+        ($($bounds:tt)*)
+    ) => {
+        $crate::__pg_inner!{
+            $other
+            ($($struct_params)* $lifetime: $($bounds)*,)
+            ($($impl_params)* $lifetime: $($bounds)*,)
+            ($($impl_args)* $lifetime,)
+            ($($phantoms)* &$lifetime (),)
+            ($($rem)*)
+        }
+    };
+}
+
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __pg_type_param_bounds {
@@ -458,3 +458,181 @@ macro_rules! __ipg_parsed_generics {
         }
     }
 }
+
+
+
+/// For parsing `const` and `static` items,
+/// passing their components to a callback macro.
+/// 
+/// This only parses items without generic parameters,
+/// (eg: `pub const FOO: u32 = 10;`, `static mut BAR: &str = "bar";`).
+/// 
+/// The type and initializer are passed as `ty` and `expr` fragments,
+/// which the callback macro can only parse as `$type:ty`/`$init:expr`, or as a `tt`.
+/// 
+/// The item must be passed as tokens, because `$item:item` fragments can't be parsed
+/// by `macro_rules!` macros.
+/// 
+/// # Example
+/// 
+/// ### Basic
+/// 
+/// Basic example of using this macro, and what it passes to a callback macro.
+/// 
+/// ```rust
+/// use core_extensions::parse_const_item;
+/// 
+/// assert_eq!(hello(), "world");
+/// 
+/// // parse_const_item invokes `bar` here
+/// parse_const_item!{
+///     crate::bar!{
+///         // The first tokens passed to the `bar` macro
+///         hello "world" foo bar 
+///     }
+///     (
+///         #[foo]
+///         pub const FOO: [u32; 2] = [3, 5];
+///     )
+/// }
+/// 
+/// #[macro_export]
+/// macro_rules! bar {
+///     (
+///         $fn_name:ident $returns:literal foo bar 
+///         // the attributes
+///         (#[foo])
+///         // the visibility
+///         (pub)
+///         // the kind of item, can be `const`, `static`, or `static mut`
+///         (const)
+///         // the name of the item
+///         (FOO)
+///         // the type of the item
+///         ($type:ty)
+///         // the initializer of the item
+///         ($init:expr)
+///     ) => {
+///         fn $fn_name() -> &'static str {
+///             $returns
+///         }
+///     }
+/// }
+/// 
+/// ```
+/// 
+/// ### Documented alias
+/// 
+/// This example declares a `const` along with a documented copy of it.
+/// 
+/// ```rust
+/// use core_extensions::parse_const_item;
+/// 
+/// macro_rules! with_documented_copy {
+///     ($copy:ident $($item:tt)*) => {
+///         $($item)*
+/// 
+///         parse_const_item!{
+///             __with_documented_copy!{$copy}
+///             ($($item)*)
+///         }
+///     }
+/// }
+/// 
+/// macro_rules! __with_documented_copy {
+///     (
+///         $copy:ident
+///         ($(#[$attr:meta])*)
+///         ($($vis:tt)*)
+///         (const)
+///         ($name:ident)
+///         ($type:ty)
+///         ($init:expr)
+///     ) => {
+///         $(#[$attr])*
+///         /// 
+///         /// This is a copy of another constant.
+///         $($vis)* const $copy: $type = $name;
+///     }
+/// }
+/// 
+/// with_documented_copy!{
+///     PAIR_COPY
+///     /// A pair of a number and its name.
+///     pub const PAIR: (u32, &str) = (3, "five");
+/// }
+/// 
+/// assert_eq!(PAIR_COPY, (3, "five"));
+/// 
+/// ```
+/// 
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "item_parsing")))]
+#[macro_export]
+macro_rules! parse_const_item {
+    (
+        $(:: $(@$leading:tt@)? )? $first:ident $(:: $trailing:ident)* ! {$($prefix:tt)*}
+
+        ($($item:tt)*)
+    ) => {
+        $crate::__pci_inner!{
+            (($(:: $(@$leading@)? )? $first $(:: $trailing)*) {$($prefix)*})
+            $($item)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pci_inner {
+    (
+        $path_prefix:tt
+        $(#[$($attr:tt)*])*
+        $(pub $(($($vis:tt)*))?)?
+        const $name:tt : $type:ty = $init:expr $(;)?
+    ) => {
+        $crate::__pci_output!{
+            $path_prefix
+            ($(#[$($attr)*])*) ($(pub $(($($vis)*))?)?) (const) ($name) ($type) ($init)
+        }
+    };
+    (
+        $path_prefix:tt
+        $(#[$($attr:tt)*])*
+        $(pub $(($($vis:tt)*))?)?
+        static mut $name:tt : $type:ty = $init:expr $(;)?
+    ) => {
+        $crate::__pci_output!{
+            $path_prefix
+            ($(#[$($attr)*])*) ($(pub $(($($vis)*))?)?) (static mut) ($name) ($type) ($init)
+        }
+    };
+    (
+        $path_prefix:tt
+        $(#[$($attr:tt)*])*
+        $(pub $(($($vis:tt)*))?)?
+        static $name:tt : $type:ty = $init:expr $(;)?
+    ) => {
+        $crate::__pci_output!{
+            $path_prefix
+            ($(#[$($attr)*])*) ($(pub $(($($vis)*))?)?) (static) ($name) ($type) ($init)
+        }
+    };
+    ($path_prefix:tt $($item:tt)*) => {
+        compile_error!{concat!(
+            "Expected a `const` or `static` item, found:\n\t",
+            $(stringify!($item),)*
+        )}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pci_output {
+    ((($($path:tt)*) {$($prefix:tt)*}) $($components:tt)*) => {
+        $($path)* ! {
+            $($prefix)*
+
+            $($components)*
+        }
+    };
+}
@@ -41,6 +41,9 @@
 /// - [`iterate`](#iterate):
 /// Nested iteration over multiple lists.
 /// 
+/// - [`fold`](#fold):
+/// Left-folds a list, nesting invocations of the callback macro.
+/// 
 /// The methods that take integer arguments use
 /// [the `<number>` syntax](./macro.gen_ident_range.html#number-syntax) from [`gen_ident_range`]
 /// 
@@ -960,6 +963,49 @@
 /// }
 /// ```
 /// 
+/// # `fold`
+/// 
+/// Left-folds a list of token trees,
+/// passing the accumulator and each element to the callback macro.
+/// 
+/// For `callback!{prefix} fold(init): (a b c)` this expands to
+/// ```text
+/// callback!{prefix (callback!{prefix (callback!{prefix (init) (a)}) (b)}) (c)}
+/// ```
+/// The invocations are nested, so they're expanded from the outermost inwards,
+/// which means that this is mostly useful in type and expression position,
+/// where the callback can parse the accumulator as a `$acc:ty` or `$acc:expr`.
+/// 
+/// If the list is empty, this expands to the `init` tokens.
+/// 
+/// ### Example
+/// 
+/// ```rust
+/// use core_extensions::tokens_method;
+/// 
+/// use std::marker::PhantomData;
+/// 
+/// struct Or<L, R>(PhantomData<(L, R)>);
+/// 
+/// struct A;
+/// struct B;
+/// struct C;
+/// 
+/// macro_rules! or_type {
+///     (($acc:ty) ($elem:ty)) => { Or<$acc, $elem> }
+/// }
+/// 
+/// type Nested = tokens_method!{or_type!{} fold(A): (B C)};
+/// 
+/// let _: Nested = Or::<Or<A, B>, C>(PhantomData);
+/// 
+/// // With an empty list, this expands to the initial value.
+/// type Init = tokens_method!{or_type!{} fold(A): ()};
+/// 
+/// let _: Init = A;
+/// 
+/// ```
+/// 
 /// <span id="range-fn"></span>
 /// # `range` iterator function
 /// 
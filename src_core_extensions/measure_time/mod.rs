@@ -1,7 +1,7 @@
 //! Time measurement.
 //!
 
-use std_::time::Duration;
+use std_::time::{Duration, Instant};
 
 /// Measures the time taken by `f` to execute, returning a pair of `(Duration, T)`.
 #[inline(never)]
@@ -9,7 +9,7 @@ pub fn measure<F, T>(f: F) -> (Duration, T)
 where
     F: FnOnce() -> T,
 {
-    let now = ::std_::time::Instant::now();
+    let now = Instant::now();
     let ret = f();
     let duration = now.elapsed();
     let microseconds = Duration::from(duration);
@@ -30,3 +30,93 @@ where
     }
 }
 
+
+
+/// Prints how long a function took to run, every time that it returns.
+///
+/// The function's signature, generics, and `async`-ness are preserved.
+/// The elapsed time is printed to stderr along with the function name,
+/// including when the function returns early with `return` or `?`.
+///
+/// For `async` functions, the measured time is the wall-clock time
+/// from the first poll of the returned future until it completes,
+/// including the time spent waiting on other futures.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::measure_time::timed;
+///
+/// #[timed]
+/// fn fibonacci(n: u32) -> u64 {
+///     let (mut prev, mut curr) = (0, 1);
+///     for _ in 0..n {
+///         let next = prev + curr;
+///         prev = curr;
+///         curr = next;
+///     }
+///     prev
+/// }
+///
+/// #[timed]
+/// fn parse_sum(list: &[&str]) -> Result<u64, std::num::ParseIntError> {
+///     let mut sum = 0;
+///     for s in list {
+///         sum += s.parse::<u64>()?;
+///     }
+///     Ok(sum)
+/// }
+///
+/// // Prints something like "`fibonacci` took 1.2µs" to stderr
+/// assert_eq!(fibonacci(10), 55);
+///
+/// assert_eq!(parse_sum(&["3", "5", "8"]), Ok(16));
+/// assert!(parse_sum(&["3", "five"]).is_err());
+///
+/// ```
+///
+/// # Crate path
+///
+/// The `crate = path` argument overrides the path to `core_extensions`,
+/// for when the crate is renamed or used through a reexport.
+/// The path defaults to `::core_extensions`.
+///
+/// ```rust
+/// extern crate core_extensions as ce;
+///
+/// use ce::measure_time::timed;
+///
+/// #[timed(crate = ce)]
+/// fn double(n: u32) -> u32 {
+///     n * 2
+/// }
+///
+/// assert_eq!(double(21), 42);
+///
+/// ```
+#[cfg(feature = "timed")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "timed")))]
+pub use core_extensions_proc_macros::timed;
+
+#[doc(hidden)]
+pub struct __TimedGuard {
+    name: &'static str,
+    start: Instant,
+}
+
+impl __TimedGuard {
+    #[doc(hidden)]
+    #[inline]
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for __TimedGuard {
+    fn drop(&mut self) {
+        eprintln!("`{}` took {:?}", self.name, self.start.elapsed());
+    }
+}
@@ -6,9 +6,13 @@ mod option_extensions;
 
 mod result_extensions;
 
+mod poll_extensions;
+
 #[doc(inline)]
 pub use self::option_extensions::*;
 #[doc(inline)]
 pub use self::result_extensions::*;
 #[doc(inline)]
+pub use self::poll_extensions::*;
+#[doc(inline)]
 pub use self::result_like::{ResultLike, ResultLikeExt};
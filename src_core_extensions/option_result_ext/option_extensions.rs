@@ -1,6 +1,7 @@
 #[cfg(feature = "std")]
 use std_::error;
 use std_::fmt;
+use std_::slice;
 
 use super::ResultLike;
 use type_identity::TypeIdentity;
@@ -76,10 +77,348 @@ pub trait OptionExt<T>: ResultLike + TypeIdentity<Type = Option<T>> + Sized {
             None => None,
         }
     }
+
+    /// Returns `Some` if exactly one of `self` and `other` is `Some`,
+    /// otherwise returns `None`.
+    ///
+    /// This is equivalent to the [`Option::xor`] method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// assert_eq!(Some(3).xor_(None), Some(3));
+    /// assert_eq!(None.xor_(Some(5)), Some(5));
+    /// assert_eq!(Some(3).xor_(Some(5)), None);
+    /// assert_eq!(None::<u32>.xor_(None), None);
+    ///
+    /// ```
+    ///
+    /// [`Option::xor`]: https://doc.rust-lang.org/std/option/enum.Option.html#method.xor
+    #[inline]
+    fn xor_(self, other: Option<T>) -> Option<T> {
+        match (self.into_type(), other) {
+            (Some(x), None) | (None, Some(x)) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Merges `self` and `other` into a single `Option`,
+    /// combining the values with `f` if both are `Some`,
+    /// otherwise returning whichever one is `Some`.
+    ///
+    /// `f` is only called if both `self` and `other` are `Some`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// assert_eq!(Some(3).merge(Some(5), |l, r| l + r), Some(8));
+    /// assert_eq!(Some(3).merge(None, |l, r| l + r), Some(3));
+    /// assert_eq!(None.merge(Some(5), |l, r| l + r), Some(5));
+    /// assert_eq!(None::<u32>.merge(None, |l, r| l + r), None);
+    ///
+    /// // Overlaying an optional setting over a default
+    /// let default_timeout = Some(30);
+    /// let user_timeout = Some(10);
+    /// assert_eq!(default_timeout.merge(user_timeout, |_, user| user), Some(10));
+    ///
+    /// ```
+    #[inline]
+    fn merge<F>(self, other: Option<T>, f: F) -> Option<T>
+    where
+        F: FnOnce(T, T) -> T,
+    {
+        match (self.into_type(), other) {
+            (Some(l), Some(r)) => Some(f(l, r)),
+            (Some(x), None) | (None, Some(x)) => Some(x),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns `Some` with a tuple of the values in `self`, `b`, and `c`
+    /// if all of them are `Some`, otherwise returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// assert_eq!(Some(3).zip3(Some("foo"), Some('c')), Some((3, "foo", 'c')));
+    ///
+    /// assert_eq!(None::<u32>.zip3(Some("foo"), Some('c')), None);
+    /// assert_eq!(Some(3).zip3(None::<&str>, Some('c')), None);
+    /// assert_eq!(Some(3).zip3(Some("foo"), None::<char>), None);
+    ///
+    /// ```
+    #[inline]
+    fn zip3<B, C>(self, b: Option<B>, c: Option<C>) -> Option<(T, B, C)> {
+        match (self.into_type(), b, c) {
+            (Some(a), Some(b), Some(c)) => Some((a, b, c)),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some` with a tuple of the values in `self`, `b`, `c`, and `d`
+    /// if all of them are `Some`, otherwise returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// let host = Some("localhost");
+    /// let port = Some(8080);
+    /// let user = Some("admin");
+    /// let timeout = Some(30.0);
+    ///
+    /// assert_eq!(host.zip4(port, user, timeout), Some(("localhost", 8080, "admin", 30.0)));
+    ///
+    /// assert_eq!(None::<&str>.zip4(port, user, timeout), None);
+    /// assert_eq!(host.zip4(None::<u16>, user, timeout), None);
+    /// assert_eq!(host.zip4(port, None::<&str>, timeout), None);
+    /// assert_eq!(host.zip4(port, user, None::<f64>), None);
+    ///
+    /// ```
+    #[inline]
+    fn zip4<B, C, D>(self, b: Option<B>, c: Option<C>, d: Option<D>) -> Option<(T, B, C, D)> {
+        match (self.into_type(), b, c, d) {
+            (Some(a), Some(b), Some(c), Some(d)) => Some((a, b, c, d)),
+            _ => None,
+        }
+    }
+
+    /// Returns a slice containing the value in `self` if it's `Some`,
+    /// otherwise returns an empty slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// assert_eq!(Some(3).as_slice_(), &[3]);
+    /// assert_eq!(None::<u32>.as_slice_(), &[]);
+    ///
+    /// let opts = [Some(3), None, Some(5)];
+    /// assert_eq!(opts.iter().flat_map(|x| x.as_slice_()).sum::<u32>(), 8);
+    ///
+    /// ```
+    #[inline]
+    fn as_slice_(&self) -> &[T] {
+        match self.as_type() {
+            Some(x) => slice::from_ref(x),
+            None => &[],
+        }
+    }
+
+    /// Converts `self` into a `Result`, calling `f` to construct the error if it's `None`.
+    ///
+    /// This is equivalent to the [`Option::ok_or_else`] method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// assert_eq!(Some(3).ok_or_else_(|| "nope"), Ok(3));
+    /// assert_eq!(None::<u32>.ok_or_else_(|| "nope"), Err("nope"));
+    ///
+    /// ```
+    ///
+    /// [`Option::ok_or_else`]: https://doc.rust-lang.org/std/option/enum.Option.html#method.ok_or_else
+    #[inline]
+    fn ok_or_else_<E, F>(self, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+    {
+        match self.into_type() {
+            Some(x) => Ok(x),
+            None => Err(f()),
+        }
+    }
+
+    /// Gets a mutable reference to the contents,
+    /// inserting the value returned by `f` if `self` is `None`.
+    ///
+    /// `f` is only called if `self` is `None`,
+    /// and if it returns an error, `self` is left as `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// let mut opt = None::<u32>;
+    ///
+    /// assert_eq!(opt.get_or_try_insert_with(|| "oops".parse::<u32>()).is_err(), true);
+    /// assert_eq!(opt, None);
+    ///
+    /// assert_eq!(opt.get_or_try_insert_with(|| "13".parse::<u32>()), Ok(&mut 13));
+    /// assert_eq!(opt, Some(13));
+    ///
+    /// *opt.get_or_try_insert_with(|| -> Result<u32, ()> { unreachable!() }).unwrap() += 1;
+    /// assert_eq!(opt, Some(14));
+    ///
+    /// ```
+    #[inline]
+    fn get_or_try_insert_with<E, F>(&mut self, f: F) -> Result<&mut T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        let this = self.as_type_mut();
+        if this.is_none() {
+            *this = Some(f()?);
+        }
+        match this {
+            Some(x) => Ok(x),
+            None => unreachable!(),
+        }
+    }
+
+    /// Takes the value out of `self` if it's `Some` and `pred` returns true for it,
+    /// leaving `None` in its place.
+    ///
+    /// `pred` gets a mutable reference to the value,
+    /// so it can modify the value even if it returns false.
+    ///
+    /// This is equivalent to the [`Option::take_if`] method, 
+    /// which requires Rust 1.80.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// let mut opt = Some(3);
+    ///
+    /// assert_eq!(opt.take_if_(|x| *x % 2 == 0), None);
+    /// assert_eq!(opt, Some(3));
+    ///
+    /// assert_eq!(opt.take_if_(|x| { *x += 1; *x % 2 == 0 }), Some(4));
+    /// assert_eq!(opt, None);
+    ///
+    /// assert_eq!(opt.take_if_(|_| true), None);
+    ///
+    /// ```
+    ///
+    /// [`Option::take_if`]: https://doc.rust-lang.org/std/option/enum.Option.html#method.take_if
+    #[inline]
+    fn take_if_<F>(&mut self, pred: F) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        let this = self.as_type_mut();
+        let take = match this.as_mut() {
+            Some(x) => pred(x),
+            None => false,
+        };
+        if take {
+            this.take()
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the value of `self` with the return value of `f`,
+    /// which takes the previous value of `self`.
+    ///
+    /// If `f` panics, `self` is left as `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// let mut opt = Some(3);
+    ///
+    /// opt.replace_with(|x| x.map(|x| x * 2));
+    /// assert_eq!(opt, Some(6));
+    ///
+    /// opt.replace_with(|x| x.filter(|x| *x > 10));
+    /// assert_eq!(opt, None);
+    ///
+    /// opt.replace_with(|x| x.or(Some(100)));
+    /// assert_eq!(opt, Some(100));
+    ///
+    /// ```
+    #[inline]
+    fn replace_with<F>(&mut self, f: F)
+    where
+        F: FnOnce(Option<T>) -> Option<T>,
+    {
+        let this = self.as_type_mut();
+        *this = f(this.take());
+    }
+
+    /// Asserts that this is a `None`, 
+    /// panicking with `msg` and the contained value if it's a `Some`.
+    ///
+    /// This is useful for checking invariants where a value is expected to be absent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// None::<u32>.expect_none("there should be no leftover value");
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// This panics with the message `"there should be no leftover value: 3"`
+    ///
+    /// ```should_panic
+    /// use core_extensions::OptionExt;
+    ///
+    /// Some(3).expect_none("there should be no leftover value");
+    /// ```
+    #[inline]
+    fn expect_none(self, msg: &str)
+    where
+        T: fmt::Debug,
+    {
+        if let Some(x) = self.into_type() {
+            expect_none_failed(msg, &x);
+        }
+    }
+
+    /// Asserts that this is a `None`, 
+    /// panicking with the contained value if it's a `Some`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::OptionExt;
+    ///
+    /// None::<&str>.assert_none();
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// ```should_panic
+    /// use core_extensions::OptionExt;
+    ///
+    /// Some("hello").assert_none();
+    /// ```
+    #[inline]
+    fn assert_none(self)
+    where
+        T: fmt::Debug,
+    {
+        self.expect_none("called `OptionExt::assert_none` on a `Some` value")
+    }
 }
 
 impl<T> OptionExt<T> for Option<T> {}
 
+#[cold]
+#[inline(never)]
+fn expect_none_failed(msg: &str, value: &dyn fmt::Debug) -> ! {
+    panic!("{}: {:?}", msg, value)
+}
+
 impl<T> ResultLike for Option<T> {
     type Item = T;
     type Error = IsNoneError;
@@ -216,4 +555,44 @@ for_tuple!{A 0}
 for_tuple!{A 0, B 1}
 for_tuple!{A 0, B 1, C 2}
 for_tuple!{A 0, B 1, C 2, D 3}
-for_tuple!{A 0, B 1, C 2, D 3, E 4}
\ No newline at end of file
+for_tuple!{A 0, B 1, C 2, D 3, E 4}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge() {
+        let mut calls = 0;
+        let mut min = |l: &'static str, r: &'static str| {
+            calls += 1;
+            if l < r { l } else { r }
+        };
+
+        assert_eq!(Some("foo").merge(Some("bar"), &mut min), Some("bar"));
+        assert_eq!(Some("foo").merge(None, &mut min), Some("foo"));
+        assert_eq!(None.merge(Some("bar"), &mut min), Some("bar"));
+        assert_eq!(None.merge(None, &mut min), None);
+
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn expect_none_on_none() {
+        None::<u32>.expect_none("unreachable");
+        None::<u32>.assert_none();
+    }
+
+    #[test]
+    #[should_panic(expected = "there should be no leftover value: [3, 5]")]
+    fn expect_none_on_some() {
+        Some([3, 5]).expect_none("there should be no leftover value");
+    }
+
+    #[test]
+    #[should_panic(expected = "`Some` value: \"hello\"")]
+    fn assert_none_on_some() {
+        Some("hello").assert_none();
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(feature = "std")]
+use std_::error;
+use std_::fmt;
+use std_::task::Poll;
+
+use super::ResultLike;
+
+/// Treats `Poll::Ready` as the item variant, and `Poll::Pending` as the error variant.
+///
+/// # Example
+///
+/// ```
+/// use core_extensions::{ResultLike, ResultLikeExt};
+/// use core_extensions::option_result_ext::IsPendingError;
+///
+/// use std::task::Poll;
+///
+/// assert_eq!(Poll::Ready(5).into_result_(), Ok(5));
+/// assert_eq!(Poll::<u32>::Pending.into_result_(), Err(IsPendingError::new()));
+///
+/// assert!(Poll::Ready(5).is_item());
+/// assert!(Poll::<u32>::Pending.is_error());
+///
+/// assert_eq!(Poll::Ready(8).unwrap_or_(13), 8);
+/// assert_eq!(Poll::Pending.unwrap_or_(13), 13);
+///
+/// assert_eq!(Poll::from_item(21), Poll::Ready(21));
+/// assert_eq!(Poll::<u32>::from_error(IsPendingError::new()), Poll::Pending);
+///
+/// ```
+impl<T> ResultLike for Poll<T> {
+    type Item = T;
+    type Error = IsPendingError;
+
+    #[inline]
+    fn is_item(&self) -> bool {
+        self.is_ready()
+    }
+    #[inline]
+    #[cfg_attr(feature = "track_caller", track_caller)]
+    fn into_result_(self) -> Result<Self::Item, Self::Error> {
+        match self {
+            Poll::Ready(x) => Ok(x),
+            Poll::Pending => Err(IsPendingError::new()),
+        }
+    }
+
+    #[inline]
+    fn from_item(item: Self::Item) -> Self {
+        Poll::Ready(item)
+    }
+
+    #[inline]
+    fn from_error(_err: Self::Error) -> Self {
+        Poll::Pending
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+/// The [`ResultLike::Error`] for `Poll`
+/// 
+/// [`ResultLike::Error`]: trait.ResultLike.html#associatedtype.Error
+#[derive(Debug, Copy, Clone)]
+pub struct IsPendingError (
+    #[cfg(feature = "track_caller")]
+    &'static std_::panic::Location<'static>,
+
+    #[cfg(not(feature = "track_caller"))]
+    (),
+);
+
+impl IsPendingError {
+    /// Constructs an IsPendingError
+    #[cfg_attr(feature = "track_caller", track_caller)]
+    #[inline]
+    pub fn new() -> Self {
+        cfg_if!(
+            (feature = "track_caller") {
+                Self(std_::panic::Location::caller())
+            } else {
+                Self(())
+            }
+        )
+    }
+}
+
+impl Default for IsPendingError {
+    #[cfg_attr(feature = "track_caller", track_caller)]
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std_::cmp::PartialEq for IsPendingError {
+    fn eq(&self, _: &IsPendingError) -> bool {
+        true
+    }
+}
+
+impl std_::cmp::Eq for IsPendingError {}
+
+impl fmt::Display for IsPendingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("attempted to unwrap a Poll that was Pending")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for IsPendingError {}
@@ -56,6 +56,120 @@ pub trait ResultExt<T, E>: Sized + ResultLike + TypeIdentity<Type = Result<T, E>
     {
         self.into_type().map_err(|e| format!("{:#?}", e))
     }
+
+    /// Converts a `Result<Result<U, E>, E>` into a `Result<U, E>`.
+    ///
+    /// This is equivalent to `.and_then(|x| x)`.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::ResultExt;
+    ///
+    /// let ok_ok: Result<Result<u32, &str>, &str> = Ok(Ok(3));
+    /// let ok_err: Result<Result<u32, &str>, &str> = Ok(Err("inner"));
+    /// let err: Result<Result<u32, &str>, &str> = Err("outer");
+    ///
+    /// assert_eq!(ok_ok.flatten_(), Ok(3));
+    /// assert_eq!(ok_err.flatten_(), Err("inner"));
+    /// assert_eq!(err.flatten_(), Err("outer"));
+    ///
+    /// ```
+    #[inline]
+    fn flatten_<U>(self) -> Result<U, E>
+    where
+        T: TypeIdentity<Type = Result<U, E>>,
+    {
+        match self.into_type() {
+            Ok(x) => x.into_type(),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Calls `f` with a reference to the `Ok` value, returning `self` unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::ResultExt;
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let ok: Result<u32, &str> = Ok(3);
+    /// assert_eq!(ok.tap_ok(|x| log.push(format!("got {}", x))), Ok(3));
+    ///
+    /// let err: Result<u32, &str> = Err("oh no");
+    /// assert_eq!(err.tap_ok(|x| log.push(format!("got {}", x))), Err("oh no"));
+    ///
+    /// assert_eq!(log, ["got 3"]);
+    ///
+    /// ```
+    #[inline]
+    fn tap_ok<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let Ok(x) = self.as_type() {
+            f(x);
+        }
+        self
+    }
+
+    /// Calls `f` with a reference to the `Err` value, returning `self` unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::ResultExt;
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let ok: Result<u32, &str> = Ok(3);
+    /// assert_eq!(ok.tap_err(|e| log.push(format!("error: {}", e))), Ok(3));
+    ///
+    /// let err: Result<u32, &str> = Err("oh no");
+    /// assert_eq!(err.tap_err(|e| log.push(format!("error: {}", e))), Err("oh no"));
+    ///
+    /// assert_eq!(log, ["error: oh no"]);
+    ///
+    /// ```
+    #[inline]
+    fn tap_err<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&E),
+    {
+        if let Err(e) = self.as_type() {
+            f(e);
+        }
+        self
+    }
+
+    /// Maps the `Ok` value with `f`, returning `U::default()` if `self` is an `Err`.
+    ///
+    /// This is equivalent to `.map_or_else(|_| U::default(), f)`.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::ResultExt;
+    ///
+    /// let ok: Result<u32, &str> = Ok(5);
+    /// assert_eq!(ok.map_or_default(|x| x * 2), 10);
+    ///
+    /// let err: Result<u32, &str> = Err("oh no");
+    /// assert_eq!(err.map_or_default(|x| x * 2), 0);
+    ///
+    /// let err: Result<u32, &str> = Err("oh no");
+    /// assert_eq!(err.map_or_default(|x| x.to_string()), "");
+    ///
+    /// ```
+    #[inline]
+    fn map_or_default<U, F>(self, f: F) -> U
+    where
+        U: Default,
+        F: FnOnce(T) -> U,
+    {
+        match self.into_type() {
+            Ok(x) => f(x),
+            Err(_) => U::default(),
+        }
+    }
 }
 
 impl<E, T> ResultExt<T, E> for Result<T, E> {}
@@ -1,6 +1,7 @@
 use std_::{
     convert::Infallible,
     fmt,
+    option,
 };
 
 use utils::impossible;
@@ -327,6 +328,61 @@ pub trait ResultLikeExt: ResultLike {
         }
     }
 
+    /// Converts `self` into a `Result`,
+    /// mapping the item with `f`, and the error with `g`.
+    /// 
+    /// # Example 
+    /// 
+    /// ```
+    /// use core_extensions::ResultLikeExt;
+    /// use core_extensions::option_result_ext::IsNoneError;
+    /// 
+    /// assert_eq!(Some(3).map_to_result(|x| x * 2, |_| "none"), Ok(6));
+    /// assert_eq!(None::<u32>.map_to_result(|x| x * 2, |_| "none"), Err("none"));
+    /// 
+    /// assert_eq!(Ok::<u32, u32>(5).map_to_result(|x| x + 1, |e| e * 2), Ok::<u32, u32>(6));
+    /// assert_eq!(Err::<u32, u32>(5).map_to_result(|x| x + 1, |e| e * 2), Err::<u32, u32>(10));
+    /// 
+    /// let res: Result<String, IsNoneError> = Some(8).map_to_result(|x| x.to_string(), |e| e);
+    /// assert_eq!(res, Ok("8".to_string()));
+    /// 
+    /// ```
+    #[inline]
+    fn map_to_result<U, E, F, G>(self, f: F, g: G) -> Result<U, E>
+    where
+        F: FnOnce(Self::Item) -> U,
+        G: FnOnce(Self::Error) -> E,
+    {
+        match self.into_result_() {
+            Ok(x) => Ok(f(x)),
+            Err(e) => Err(g(e)),
+        }
+    }
+
+    /// Converts `self` into an iterator which yields the item if it's the item variant,
+    /// and nothing if it's the error variant.
+    ///
+    /// This allows any `ResultLike` type to be used in `flat_map` and `flatten`.
+    /// 
+    /// # Example 
+    /// 
+    /// ```
+    /// use core_extensions::ResultLikeExt;
+    /// 
+    /// assert_eq!(Some(3).into_iter_().collect::<Vec<_>>(), vec![3]);
+    /// assert_eq!(None::<u32>.into_iter_().next(), None);
+    /// 
+    /// let list: Vec<Result<i32, ()>> = vec![Ok(3), Err(()), Ok(5), Err(()), Ok(8)];
+    /// 
+    /// let items = list.into_iter().flat_map(ResultLikeExt::into_iter_).collect::<Vec<_>>();
+    /// assert_eq!(items, vec![3, 5, 8]);
+    /// 
+    /// ```
+    #[inline]
+    fn into_iter_(self) -> option::IntoIter<Self::Item> {
+        self.into_result_().ok().into_iter()
+    }
+
     /// Unwraps the item variant.
     ///
     /// # Panic
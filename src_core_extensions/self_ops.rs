@@ -1,5 +1,11 @@
 //! Universal extension trait.Implemented for every type.
 
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    rc::Rc,
+    sync::Arc,
+};
 
 /// Extension trait for every type.
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "self_ops")))]
@@ -237,6 +243,74 @@ pub trait SelfOps {
     {
         self.as_mut()
     }
+    /// Moves `self` into a `Box`, using method notation.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::SelfOps;
+    ///
+    /// let boxed: Box<u32> = 3.boxed();
+    /// assert_eq!(*boxed, 3);
+    ///
+    /// let boxed: Box<Vec<u32>> = vec![3, 5].mutated(|v| v.push(8)).boxed();
+    /// assert_eq!(*boxed, vec![3, 5, 8]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    #[inline(always)]
+    fn boxed(self) -> Box<Self>
+    where
+        Self: Sized,
+    {
+        Box::new(self)
+    }
+    /// Moves `self` into an `Rc`, using method notation.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::SelfOps;
+    ///
+    /// use std::rc::Rc;
+    ///
+    /// let rc: Rc<String> = "hello".to_string().rc();
+    /// let other = rc.clone();
+    ///
+    /// assert_eq!(*other, "hello");
+    /// assert_eq!(Rc::strong_count(&rc), 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    #[inline(always)]
+    fn rc(self) -> Rc<Self>
+    where
+        Self: Sized,
+    {
+        Rc::new(self)
+    }
+    /// Moves `self` into an `Arc`, using method notation.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::SelfOps;
+    ///
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let arc: Arc<Mutex<u32>> = Mutex::new(0).arc();
+    /// let other = arc.clone();
+    ///
+    /// *other.lock().unwrap() += 10;
+    ///
+    /// assert_eq!(*arc.lock().unwrap(), 10);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    #[inline(always)]
+    fn arc(self) -> Arc<Self>
+    where
+        Self: Sized,
+    {
+        Arc::new(self)
+    }
     /// Drops `self` using method notation.
     /// Alternative to `std::mem::drop`.
     ///
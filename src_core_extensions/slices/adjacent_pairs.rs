@@ -0,0 +1,33 @@
+use std_::slice::Windows;
+
+/// Iterator over pairs of adjacent elements of a slice,
+/// yielding them as tuples of references.
+///
+/// Look [here](trait.ValSliceExt.html#method.adjacent_pairs) for examples.
+#[derive(Debug, Clone)]
+pub struct AdjacentPairs<'a, T: 'a> {
+    pub(super) iter: Windows<'a, T>,
+}
+
+impl<'a, T> Iterator for AdjacentPairs<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a T, &'a T)> {
+        self.iter.next().map(|w| (&w[0], &w[1]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for AdjacentPairs<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a T, &'a T)> {
+        self.iter.next_back().map(|w| (&w[0], &w[1]))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for AdjacentPairs<'a, T> {}
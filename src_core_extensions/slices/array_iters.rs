@@ -0,0 +1,116 @@
+//! Iterators over slices that yield references to arrays.
+
+use std_::slice::{ChunksExact, Windows};
+
+/// Converts a slice of length `N` into a reference to an array of length `N`.
+///
+/// # Safety
+///
+/// `slice.len()` must equal `N`.
+#[inline(always)]
+unsafe fn as_array_unchecked<T, const N: usize>(slice: &[T]) -> &[T; N] {
+    &*(slice.as_ptr() as *const [T; N])
+}
+
+//-------------------------------------------------------------------------------------------
+
+/// Iterator that maps every `N`-element window of a slice with a closure,
+/// passing each window as a reference to an array.
+///
+/// Look [here](trait.ValSliceExt.html#method.windows_map) for examples.
+#[derive(Debug, Clone)]
+pub struct WindowsMap<'a, T: 'a, F, const N: usize> {
+    pub(super) iter: Windows<'a, T>,
+    pub(super) mapper: F,
+}
+
+impl<'a, T, F, R, const N: usize> Iterator for WindowsMap<'a, T, F, N>
+where
+    F: FnMut(&'a [T; N]) -> R,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<R> {
+        let window = self.iter.next()?;
+        // Safety: `Windows` yields slices of exactly the length it was constructed with,
+        // which is `N`.
+        Some((self.mapper)(unsafe { as_array_unchecked(window) }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, F, R, const N: usize> DoubleEndedIterator for WindowsMap<'a, T, F, N>
+where
+    F: FnMut(&'a [T; N]) -> R,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<R> {
+        let window = self.iter.next_back()?;
+        // Safety: `Windows` yields slices of exactly the length it was constructed with,
+        // which is `N`.
+        Some((self.mapper)(unsafe { as_array_unchecked(window) }))
+    }
+}
+
+impl<'a, T, F, R, const N: usize> ExactSizeIterator for WindowsMap<'a, T, F, N> 
+where
+    F: FnMut(&'a [T; N]) -> R,
+{}
+
+//-------------------------------------------------------------------------------------------
+
+/// Iterator over non-overlapping `N`-element chunks of a slice,
+/// yielding each chunk as a reference to an array.
+///
+/// The trailing elements that don't fill a chunk are
+/// accessible with the [`remainder`](#method.remainder) method.
+///
+/// Look [here](trait.ValSliceExt.html#method.array_chunks_) for examples.
+#[derive(Debug, Clone)]
+pub struct ArrayChunks<'a, T: 'a, const N: usize> {
+    pub(super) iter: ChunksExact<'a, T>,
+}
+
+impl<'a, T, const N: usize> ArrayChunks<'a, T, N> {
+    /// Returns the trailing elements that don't fill an `N`-element chunk.
+    ///
+    /// The returned slice has at most `N - 1` elements.
+    #[inline]
+    pub fn remainder(&self) -> &'a [T] {
+        self.iter.remainder()
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayChunks<'a, T, N> {
+    type Item = &'a [T; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T; N]> {
+        let chunk = self.iter.next()?;
+        // Safety: `ChunksExact` yields slices of exactly the length it was constructed with,
+        // which is `N`.
+        Some(unsafe { as_array_unchecked(chunk) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for ArrayChunks<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T; N]> {
+        let chunk = self.iter.next_back()?;
+        // Safety: `ChunksExact` yields slices of exactly the length it was constructed with,
+        // which is `N`.
+        Some(unsafe { as_array_unchecked(chunk) })
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for ArrayChunks<'a, T, N> {}
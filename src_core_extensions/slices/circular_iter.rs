@@ -0,0 +1,36 @@
+use std_::{
+    iter::Chain,
+    slice::Iter,
+};
+
+/// Iterator over the elements of a slice, starting from an index,
+/// and wrapping around to the start of the slice.
+///
+/// Look [here](trait.ValSliceExt.html#method.iter_from) for examples.
+#[derive(Debug, Clone)]
+pub struct CircularIter<'a, T: 'a> {
+    pub(super) iter: Chain<Iter<'a, T>, Iter<'a, T>>,
+}
+
+impl<'a, T> Iterator for CircularIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for CircularIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for CircularIter<'a, T> {}
@@ -3,13 +3,251 @@
 //!
 //!
 
-// use ranges::RangeBounds;
-use super::{BiasDirection, SliceBias,SplitSliceWhile,RSplitSliceWhile};
+use super::{
+    AdjacentPairs, BiasDirection, ChunksWhile, CircularIter, DedupRuns, GroupRuns, SliceBias,
+    SplitAtEach, SplitInclusiveAtEach, SplitInto, SplitSliceWhile, RSplitSliceWhile,
+};
+
+#[cfg(feature = "rust_1_51")]
+use super::{ArrayChunks, WindowsMap};
+
+#[cfg(feature = "rust_1_51")]
+use std_::{
+    borrow::BorrowMut,
+    convert::TryFrom,
+};
 
 use std_::borrow::Borrow;
 use std_::cmp;
 use std_::mem;
-use std_::ops::Range;
+use std_::ops::{Bound, Range, RangeBounds};
+
+#[cfg(feature = "std")]
+use std_::{
+    collections::HashMap,
+    hash::Hash,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+// Declared with a macro so that Rust versions without const generics
+// never have to parse these methods.
+#[cfg(feature = "rust_1_51")]
+macro_rules! val_slice_ext_const_generic_methods {() => {
+    /// Returns an iterator which calls `mapper` with every `N`-element window of the slice,
+    /// passed as a reference to an array.
+    ///
+    /// The windows overlap, if the slice is shorter than `N` the iterator yields nothing.
+    ///
+    /// The returned type implements 
+    /// `DoubleEndedIterator + ExactSizeIterator<Item = R>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 4, 9, 16, 25];
+    ///
+    /// assert_eq!(
+    ///     list.windows_map(|[a, b]: &[i32; 2]| b - a).collect::<Vec<_>>(),
+    ///     vec![3, 5, 7, 9],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     list.windows_map(|w: &[i32; 3]| w.iter().sum::<i32>()).collect::<Vec<_>>(),
+    ///     vec![14, 29, 50],
+    /// );
+    ///
+    /// assert_eq!(list[..1].windows_map(|[a, b]: &[i32; 2]| b - a).next(), None);
+    ///
+    /// ```
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+    fn windows_map<'a, F, R, const N: usize>(&'a self, mapper: F) -> WindowsMap<'a, Self::Elem, F, N>
+    where
+        F: FnMut(&'a [Self::Elem; N]) -> R,
+    {
+        let this: &'a [Self::Elem] = self.borrow();
+        WindowsMap {
+            iter: this.windows(N),
+            mapper,
+        }
+    }
+
+    /// Returns an iterator over non-overlapping `N`-element chunks of the slice,
+    /// passed as references to arrays.
+    ///
+    /// The trailing elements that don't fill a chunk are not yielded,
+    /// they can be gotten with the 
+    /// [`ArrayChunks::remainder`](./struct.ArrayChunks.html#method.remainder) method.
+    ///
+    /// The returned type implements 
+    /// `DoubleEndedIterator + ExactSizeIterator<Item = &[Self::Elem; N]>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 2, 3, 4, 5];
+    ///
+    /// let mut iter = list.array_chunks_::<2>();
+    /// assert_eq!(iter.next(), Some(&[1, 2]));
+    /// assert_eq!(iter.next(), Some(&[3, 4]));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.remainder(), &[5]);
+    ///
+    /// let sums = list.array_chunks_().map(|[a, b, c]| a + b + c).collect::<Vec<i32>>();
+    /// assert_eq!(sums, vec![6]);
+    ///
+    /// assert_eq!(list.array_chunks_::<6>().len(), 0);
+    /// assert_eq!(list.array_chunks_::<6>().remainder(), &list);
+    ///
+    /// ```
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+    fn array_chunks_<const N: usize>(&self) -> ArrayChunks<'_, Self::Elem, N> {
+        let this: &[Self::Elem] = self.borrow();
+        ArrayChunks {
+            iter: this.chunks_exact(N),
+        }
+    }
+
+    /// Splits the slice into a reference to an array of its first `N` elements,
+    /// and the remaining elements.
+    ///
+    /// Returns `None` if the slice is shorter than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [3, 5, 8, 13];
+    ///
+    /// assert_eq!(list.get_first_chunk::<2>(), Some((&[3, 5], &[8, 13][..])));
+    /// assert_eq!(list.get_first_chunk::<4>(), Some((&[3, 5, 8, 13], &[][..])));
+    /// assert_eq!(list.get_first_chunk::<5>(), None);
+    ///
+    /// if let Some(([a, b, c], rest)) = list.get_first_chunk() {
+    ///     assert_eq!(a + b + c, 16);
+    ///     assert_eq!(rest, &[13]);
+    /// }
+    ///
+    /// ```
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+    fn get_first_chunk<const N: usize>(&self) -> Option<(&[Self::Elem; N], &[Self::Elem])> {
+        let this: &[Self::Elem] = self.borrow();
+        if this.len() < N {
+            return None;
+        }
+        let (chunk, rest) = this.split_at(N);
+        Some((<&[Self::Elem; N]>::try_from(chunk).ok()?, rest))
+    }
+
+    /// Splits the slice into the leading elements,
+    /// and a reference to an array of its last `N` elements.
+    ///
+    /// Returns `None` if the slice is shorter than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [3, 5, 8, 13];
+    ///
+    /// assert_eq!(list.get_last_chunk::<2>(), Some((&[3, 5][..], &[8, 13])));
+    /// assert_eq!(list.get_last_chunk::<4>(), Some((&[][..], &[3, 5, 8, 13])));
+    /// assert_eq!(list.get_last_chunk::<5>(), None);
+    ///
+    /// ```
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+    fn get_last_chunk<const N: usize>(&self) -> Option<(&[Self::Elem], &[Self::Elem; N])> {
+        let this: &[Self::Elem] = self.borrow();
+        if this.len() < N {
+            return None;
+        }
+        let (rest, chunk) = this.split_at(this.len() - N);
+        Some((rest, <&[Self::Elem; N]>::try_from(chunk).ok()?))
+    }
+
+    /// Mutable version of [`get_first_chunk`](#method.get_first_chunk).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let mut list = [3, 5, 8, 13];
+    ///
+    /// if let Some(([a, b], rest)) = list.get_first_chunk_mut() {
+    ///     std::mem::swap(a, b);
+    ///     rest[0] = 0;
+    /// }
+    /// assert_eq!(list, [5, 3, 0, 13]);
+    ///
+    /// assert_eq!(list.get_first_chunk_mut::<5>(), None);
+    ///
+    /// ```
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+    fn get_first_chunk_mut<const N: usize>(
+        &mut self,
+    ) -> Option<(&mut [Self::Elem; N], &mut [Self::Elem])>
+    where
+        Self: BorrowMut<[Self::Elem]>,
+    {
+        let this: &mut [Self::Elem] = self.borrow_mut();
+        if this.len() < N {
+            return None;
+        }
+        let (chunk, rest) = this.split_at_mut(N);
+        Some((<&mut [Self::Elem; N]>::try_from(chunk).ok()?, rest))
+    }
+
+    /// Mutable version of [`get_last_chunk`](#method.get_last_chunk).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let mut list = [3, 5, 8, 13];
+    ///
+    /// if let Some((rest, [a, b])) = list.get_last_chunk_mut() {
+    ///     std::mem::swap(a, b);
+    ///     rest[0] = 0;
+    /// }
+    /// assert_eq!(list, [0, 5, 13, 8]);
+    ///
+    /// assert_eq!(list.get_last_chunk_mut::<5>(), None);
+    ///
+    /// ```
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+    fn get_last_chunk_mut<const N: usize>(
+        &mut self,
+    ) -> Option<(&mut [Self::Elem], &mut [Self::Elem; N])>
+    where
+        Self: BorrowMut<[Self::Elem]>,
+    {
+        let this: &mut [Self::Elem] = self.borrow_mut();
+        if this.len() < N {
+            return None;
+        }
+        let at = this.len() - N;
+        let (rest, chunk) = this.split_at_mut(at);
+        Some((rest, <&mut [Self::Elem; N]>::try_from(chunk).ok()?))
+    }
+}}
 
 
 /// Extension trait for `[T]`.
@@ -18,114 +256,768 @@ pub trait ValSliceExt: SliceExt + Borrow<[<Self as SliceExt>::Elem]> {
     /// to the same value by `mapper`.
     /// 
     /// The returned type implements 
-    /// `DoubleEndedIterator<Item =`[`KeySlice`](./struct.KeySlice.html)`<Self::Elem, U>>`.
-    /// 
+    /// `DoubleEndedIterator<Item =`[`KeySlice`](./struct.KeySlice.html)`<Self::Elem, U>>`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    /// use core_extensions::slices::KeySlice;
+    /// 
+    /// {
+    ///     let list = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+    /// 
+    ///     assert_eq!(
+    ///         list.split_while(|x| x/4).collect::<Vec<_>>(),
+    ///         vec![
+    ///             KeySlice{key: 0, slice: &[0, 1, 2, 3]},
+    ///             KeySlice{key: 1, slice: &[4, 5, 6, 7]},
+    ///             KeySlice{key: 2, slice: &[8]},
+    ///         ]
+    ///     );
+    /// }
+    /// 
+    /// {
+    ///     let list = [0, 4, 1, 5, 9, 8, 7];
+    /// 
+    ///     assert_eq!(
+    ///         list.split_while(|x| x%4).collect::<Vec<_>>(),
+    ///         vec![
+    ///             KeySlice{key: 0, slice: &[0, 4]},
+    ///             KeySlice{key: 1, slice: &[1, 5, 9]},
+    ///             KeySlice{key: 0, slice: &[8]},
+    ///             KeySlice{key: 3, slice: &[7]},
+    ///         ]
+    ///     );
+    /// }
+    /// 
+    /// 
+    /// ```
+    /// 
+    fn split_while<'a, P, U>(&'a self, mut mapper: P) -> SplitSliceWhile<'a, Self::Elem, P, U>
+    where
+        P: FnMut(&'a Self::Elem) -> U,
+        U: Eq + Clone,
+    {
+        let this:&'a [Self::Elem] = self.borrow();
+        SplitSliceWhile {
+            last_left: this.first().map(&mut mapper),
+            last_right: this.last().map(&mut mapper),
+            mapper,
+            s: this,
+        }
+    }
+
+    /// A variation of [`split_while`](#method.split_while) that iterates
+    /// from the right(the order of subslices is reversed).
+    /// 
+    /// The returned type implements 
+    /// `DoubleEndedIterator<Item =`[`KeySlice`](./struct.KeySlice.html)`<Self::Elem, U>>`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    /// use core_extensions::slices::KeySlice;
+    /// 
+    /// {
+    ///     let list = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+    /// 
+    ///     assert_eq!(
+    ///         list.rsplit_while(|x| x/4 ).collect::<Vec<_>>(),
+    ///         vec![
+    ///             KeySlice{key: 2, slice: &[8]},
+    ///             KeySlice{key: 1, slice: &[4, 5, 6, 7]},
+    ///             KeySlice{key: 0, slice: &[0, 1, 2, 3]},
+    ///         ]
+    ///     );
+    /// }
+    /// 
+    /// {
+    ///     let list = [0, 4, 1, 5, 9, 8, 7];
+    /// 
+    ///     assert_eq!(
+    ///         list.rsplit_while(|x| x%4 ).collect::<Vec<_>>(),
+    ///         vec![
+    ///             KeySlice{key: 3, slice: &[7]},
+    ///             KeySlice{key: 0, slice: &[8]},
+    ///             KeySlice{key: 1, slice: &[1, 5, 9]},
+    ///             KeySlice{key: 0, slice: &[0, 4]},
+    ///         ]
+    ///     );
+    /// }
+    /// 
+    /// 
+    /// 
+    /// ```
+    /// 
+    fn rsplit_while<'a, P, U>(&'a self, mut mapper: P) -> RSplitSliceWhile<'a, Self::Elem, P, U>
+    where
+        P: FnMut(&'a Self::Elem) -> U,
+        U: Eq + Clone,
+    {
+        let this: &'a [Self::Elem] = self.borrow();
+        RSplitSliceWhile {
+            last_left: this.first().map(&mut mapper),
+            last_right: this.last().map(&mut mapper),
+            mapper,
+            s: this,
+        }
+    }
+
+    /// A variation of [`split_while`](#method.split_while) that also yields 
+    /// the range that each subslice occupies in `self`.
+    ///
+    /// Every yielded subslice is non-empty.
+    /// 
+    /// The returned type implements 
+    /// `DoubleEndedIterator<Item = (Range<usize>, `[`KeySlice`](./struct.KeySlice.html)`<Self::Elem, U>)>`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    /// use core_extensions::slices::KeySlice;
+    /// 
+    /// let list = [0, 4, 1, 5, 9, 8, 7];
+    /// 
+    /// assert_eq!(
+    ///     list.chunks_while(|x| x%4).collect::<Vec<_>>(),
+    ///     vec![
+    ///         (0..2, KeySlice{key: 0, slice: &[0, 4][..]}),
+    ///         (2..5, KeySlice{key: 1, slice: &[1, 5, 9]}),
+    ///         (5..6, KeySlice{key: 0, slice: &[8]}),
+    ///         (6..7, KeySlice{key: 3, slice: &[7]}),
+    ///     ]
+    /// );
+    /// 
+    /// let ranges = list.chunks_while(|x| x%4).rev().map(|(r, _)| r).collect::<Vec<_>>();
+    /// assert_eq!(ranges, vec![6..7, 5..6, 2..5, 0..2]);
+    /// 
+    /// for (range, chunk) in list.chunks_while(|x| *x < 5) {
+    ///     assert_eq!(&list[range], chunk.slice);
+    /// }
+    /// 
+    /// ```
+    /// 
+    fn chunks_while<'a, P, U>(&'a self, mapper: P) -> ChunksWhile<'a, Self::Elem, P, U>
+    where
+        P: FnMut(&'a Self::Elem) -> U,
+        U: Eq + Clone,
+    {
+        let this: &'a [Self::Elem] = self.borrow();
+        ChunksWhile {
+            iter: this.split_while(mapper),
+            start: 0,
+            end: this.len(),
+        }
+    }
+
+    /// Returns the subslice with all the leading and trailing elements 
+    /// for which `pred` returns true removed.
+    ///
+    /// This is the slice equivalent of 
+    /// [`str::trim_matches`](https://doc.rust-lang.org/std/primitive.str.html#method.trim_matches).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [0, 0, 1, 2, 0];
+    ///
+    /// assert_eq!(list.trim_matches_(|x| *x == 0), &[1, 2]);
+    /// assert_eq!(list.trim_matches_(|x| *x != 1), &[1]);
+    /// assert_eq!(list.trim_matches_(|x| *x < 3), &[] as &[i32]);
+    ///
+    /// ```
+    fn trim_matches_<P>(&self, mut pred: P) -> &[Self::Elem]
+    where
+        P: FnMut(&Self::Elem) -> bool,
+    {
+        self.trim_start_matches_(&mut pred).trim_end_matches_(pred)
+    }
+
+    /// Returns the subslice with all the leading elements 
+    /// for which `pred` returns true removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [0, 0, 1, 2, 0];
+    ///
+    /// assert_eq!(list.trim_start_matches_(|x| *x == 0), &[1, 2, 0]);
+    /// assert_eq!(list.trim_start_matches_(|x| *x == 1), &[0, 0, 1, 2, 0]);
+    /// assert_eq!(list.trim_start_matches_(|x| *x < 3), &[] as &[i32]);
+    ///
+    /// ```
+    fn trim_start_matches_<P>(&self, mut pred: P) -> &[Self::Elem]
+    where
+        P: FnMut(&Self::Elem) -> bool,
+    {
+        let this: &[Self::Elem] = self.borrow();
+        let start = this.iter().position(|x| !pred(x)).unwrap_or(this.len());
+        &this[start..]
+    }
+
+    /// Returns the subslice with all the trailing elements 
+    /// for which `pred` returns true removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [0, 0, 1, 2, 0];
+    ///
+    /// assert_eq!(list.trim_end_matches_(|x| *x == 0), &[0, 0, 1, 2]);
+    /// assert_eq!(list.trim_end_matches_(|x| *x == 1), &[0, 0, 1, 2, 0]);
+    /// assert_eq!(list.trim_end_matches_(|x| *x < 3), &[] as &[i32]);
+    ///
+    /// ```
+    fn trim_end_matches_<P>(&self, mut pred: P) -> &[Self::Elem]
+    where
+        P: FnMut(&Self::Elem) -> bool,
+    {
+        let this: &[Self::Elem] = self.borrow();
+        let end = this.iter().rposition(|x| !pred(x)).map_or(0, |i| i + 1);
+        &this[..end]
+    }
+
+    /// Splits the slice at the first element for which `pred` returns true,
+    /// returning the elements before and after it.
+    ///
+    /// The matched element is not included in either subslice,
+    /// this is the slice equivalent of 
+    /// [`str::split_once`](https://doc.rust-lang.org/std/primitive.str.html#method.split_once).
+    ///
+    /// Returns `None` if `pred` returns false for all elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 2, 0, 3, 4, 0, 5];
+    ///
+    /// assert_eq!(list.split_once_by(|x| *x == 0), Some((&[1, 2][..], &[3, 4, 0, 5][..])));
+    /// assert_eq!(list.split_once_by(|x| *x == 1), Some((&[][..], &[2, 0, 3, 4, 0, 5][..])));
+    /// assert_eq!(list.split_once_by(|x| *x == 5), Some((&[1, 2, 0, 3, 4, 0][..], &[][..])));
+    /// assert_eq!(list.split_once_by(|x| *x == 9), None);
+    ///
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn split_once_by<P>(&self, pred: P) -> Option<(&[Self::Elem], &[Self::Elem])>
+    where
+        P: FnMut(&Self::Elem) -> bool,
+    {
+        let this: &[Self::Elem] = self.borrow();
+        let index = this.iter().position(pred)?;
+        Some((&this[..index], &this[index + 1..]))
+    }
+
+    /// Splits the slice at the last element for which `pred` returns true,
+    /// returning the elements before and after it.
+    ///
+    /// The matched element is not included in either subslice,
+    /// this is the slice equivalent of 
+    /// [`str::rsplit_once`](https://doc.rust-lang.org/std/primitive.str.html#method.rsplit_once).
+    ///
+    /// Returns `None` if `pred` returns false for all elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 2, 0, 3, 4, 0, 5];
+    ///
+    /// assert_eq!(list.rsplit_once_by(|x| *x == 0), Some((&[1, 2, 0, 3, 4][..], &[5][..])));
+    /// assert_eq!(list.rsplit_once_by(|x| *x == 1), Some((&[][..], &[2, 0, 3, 4, 0, 5][..])));
+    /// assert_eq!(list.rsplit_once_by(|x| *x == 5), Some((&[1, 2, 0, 3, 4, 0][..], &[][..])));
+    /// assert_eq!(list.rsplit_once_by(|x| *x == 9), None);
+    ///
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn rsplit_once_by<P>(&self, pred: P) -> Option<(&[Self::Elem], &[Self::Elem])>
+    where
+        P: FnMut(&Self::Elem) -> bool,
+    {
+        let this: &[Self::Elem] = self.borrow();
+        let index = this.iter().rposition(pred)?;
+        Some((&this[..index], &this[index + 1..]))
+    }
+
+    /// Returns the index where the first occurrence of `needle` starts.
+    ///
+    /// This compares the elements of the slices for equality,
+    /// unlike [`SliceExt::contains_slice`](./trait.SliceExt.html#tymethod.contains_slice),
+    /// which checks whether `needle` is in the same memory as `self`.
+    ///
+    /// An empty `needle` is found at index `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 2, 3, 4, 2, 3];
+    ///
+    /// assert_eq!(list.find_subslice(&[2, 3]), Some(1));
+    /// assert_eq!(list.find_subslice(&[3, 4, 2]), Some(2));
+    /// assert_eq!(list.find_subslice(&[1, 2, 3, 4, 2, 3]), Some(0));
+    /// assert_eq!(list.find_subslice(&[3, 2]), None);
+    /// assert_eq!(list.find_subslice(&[1, 2, 3, 4, 2, 3, 4]), None);
+    /// assert_eq!(list.find_subslice(&[]), Some(0));
+    ///
+    /// ```
+    fn find_subslice(&self, needle: &[Self::Elem]) -> Option<usize>
+    where
+        Self::Elem: PartialEq,
+    {
+        let this: &[Self::Elem] = self.borrow();
+        if needle.is_empty() {
+            return Some(0);
+        }
+        this.windows(needle.len()).position(|w| w == needle)
+    }
+
+    /// Returns the index where the last occurrence of `needle` starts.
+    ///
+    /// This compares the elements of the slices for equality,
+    /// unlike [`SliceExt::contains_slice`](./trait.SliceExt.html#tymethod.contains_slice),
+    /// which checks whether `needle` is in the same memory as `self`.
+    ///
+    /// An empty `needle` is found at index `self.len()`,
+    /// like with [`str::rfind`](https://doc.rust-lang.org/std/primitive.str.html#method.rfind).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 2, 3, 4, 2, 3];
+    ///
+    /// assert_eq!(list.rfind_subslice(&[2, 3]), Some(4));
+    /// assert_eq!(list.rfind_subslice(&[1, 2]), Some(0));
+    /// assert_eq!(list.rfind_subslice(&[3, 2]), None);
+    /// assert_eq!(list.rfind_subslice(&[]), Some(6));
+    ///
+    /// ```
+    fn rfind_subslice(&self, needle: &[Self::Elem]) -> Option<usize>
+    where
+        Self::Elem: PartialEq,
+    {
+        let this: &[Self::Elem] = self.borrow();
+        if needle.is_empty() {
+            return Some(this.len());
+        }
+        this.windows(needle.len()).rposition(|w| w == needle)
+    }
+
+    /// Checks whether the elements of this slice are sorted in ascending order.
+    ///
+    /// Empty and single-element slices are always sorted.
+    ///
+    /// This is like the `is_sorted` method of slices,
+    /// which was stabilized after the minimum supported Rust version of this crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// assert!([1, 2, 2, 9].is_sorted_());
+    /// assert!(![1, 3, 2, 4].is_sorted_());
+    /// assert!([0.5, 1.0].is_sorted_());
+    /// assert!(![0.5, std::f64::NAN].is_sorted_());
+    /// assert!([5].is_sorted_());
+    /// assert!(<[u8]>::is_sorted_(&[]));
+    ///
+    /// ```
+    fn is_sorted_(&self) -> bool
+    where
+        Self::Elem: PartialOrd,
+    {
+        self.is_sorted_by(|l, r| l <= r)
+    }
+
+    /// Checks whether the elements of this slice are sorted,
+    /// using `is_ordered` to check whether each pair of adjacent elements is in order.
+    ///
+    /// Empty and single-element slices are always sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// assert!([9, 4, 4, 1].is_sorted_by(|l, r| l >= r));
+    /// assert!(![9, 4, 5, 1].is_sorted_by(|l, r| l >= r));
+    ///
+    /// assert!(["a", "bb", "ccc"].is_sorted_by(|l, r| l.len() < r.len()));
+    /// assert!(!["a", "bb", "cc"].is_sorted_by(|l, r| l.len() < r.len()));
+    ///
+    /// ```
+    fn is_sorted_by<F>(&self, mut is_ordered: F) -> bool
+    where
+        F: FnMut(&Self::Elem, &Self::Elem) -> bool,
+    {
+        let this: &[Self::Elem] = self.borrow();
+        this.windows(2).all(|w| is_ordered(&w[0], &w[1]))
+    }
+
+    /// Returns the most common element in the slice, along with how many times it occurs.
+    ///
+    /// If multiple elements occur the same (maximum) number of times,
+    /// this returns the one that occurs first in the slice.
+    ///
+    /// Returns `None` if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// assert_eq!([1, 2, 2, 3, 2].most_common(), Some((&2, 3)));
+    /// assert_eq!(["foo", "bar", "bar", "foo"].most_common(), Some((&"foo", 2)));
+    /// assert_eq!([5, 3, 8].most_common(), Some((&5, 1)));
+    /// assert_eq!(<[u8]>::most_common(&[]), None);
+    ///
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+    fn most_common(&self) -> Option<(&Self::Elem, usize)>
+    where
+        Self::Elem: Eq + Hash,
+    {
+        let this: &[Self::Elem] = self.borrow();
+        
+        // maps each element to its count and the index where it first occurs
+        let mut counts = HashMap::<&Self::Elem, (usize, usize)>::new();
+        for (i, elem) in this.iter().enumerate() {
+            counts.entry(elem).or_insert((0, i)).0 += 1;
+        }
+
+        counts
+            .into_iter()
+            .max_by(|(_, (l_count, l_index)), (_, (r_count, r_index))| {
+                l_count.cmp(r_count).then(r_index.cmp(l_index))
+            })
+            .map(|(elem, (count, _))| (elem, count))
+    }
+
+    /// Returns the most common element in the slice, along with how many times it occurs,
+    /// comparing elements with `Ord` instead of hashing them.
+    ///
+    /// If multiple elements occur the same (maximum) number of times,
+    /// this returns the one that occurs first in the slice.
+    ///
+    /// Returns `None` if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// assert_eq!([1, 2, 2, 3, 2].most_common_ord(), Some((&2, 3)));
+    /// assert_eq!(["foo", "bar", "bar", "foo"].most_common_ord(), Some((&"foo", 2)));
+    /// assert_eq!([5, 3, 8].most_common_ord(), Some((&5, 1)));
+    /// assert_eq!(<[u8]>::most_common_ord(&[]), None);
+    ///
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn most_common_ord(&self) -> Option<(&Self::Elem, usize)>
+    where
+        Self::Elem: Ord,
+    {
+        let this: &[Self::Elem] = self.borrow();
+
+        // Stable sorting keeps the indices of equal elements in ascending order,
+        // so the first index of every run is where that element first occurs.
+        let mut indices: Vec<usize> = (0..this.len()).collect();
+        indices.sort_by(|&l, &r| this[l].cmp(&this[r]));
+
+        let mut best: Option<(usize, usize)> = None;
+        for run in indices.split_while(|&i| &this[i]) {
+            let (first, count) = (run.slice[0], run.slice.len());
+            best = match best {
+                Some((b_first, b_count)) 
+                if b_count > count || b_count == count && b_first < first 
+                => best,
+                _ => Some((first, count)),
+            };
+        }
+        best.map(|(first, count)| (&this[first], count))
+    }
+
+    /// Returns an iterator over every pair of adjacent elements.
+    ///
+    /// This is like `self.windows(2)`, but yielding the pairs as tuples.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 2, 4, 7];
+    ///
+    /// assert_eq!(
+    ///     list.adjacent_pairs().collect::<Vec<_>>(),
+    ///     vec![(&1, &2), (&2, &4), (&4, &7)],
+    /// );
+    ///
+    /// let diffs = list.adjacent_pairs().map(|(l, r)| r - l).collect::<Vec<i32>>();
+    /// assert_eq!(diffs, vec![1, 2, 3]);
+    ///
+    /// assert_eq!([1].adjacent_pairs().next(), None);
+    /// assert_eq!(<[u8]>::adjacent_pairs(&[]).next(), None);
+    ///
+    /// ```
+    fn adjacent_pairs<'a>(&'a self) -> AdjacentPairs<'a, Self::Elem> {
+        let this: &[Self::Elem] = self.borrow();
+        AdjacentPairs {
+            iter: this.windows(2),
+        }
+    }
+
+    /// Returns an iterator over the subslices separated by elements 
+    /// for which `pred` returns true, not including those separators.
+    ///
+    /// This is like the `split` method of slices.
+    ///
+    /// The returned type implements 
+    /// `DoubleEndedIterator<Item = &[Self::Elem]>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 0, 2, 3, 0, 4];
+    ///
+    /// assert_eq!(
+    ///     list.split_at_each(|x| *x == 0).collect::<Vec<_>>(),
+    ///     vec![&[1][..], &[2, 3], &[4]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     list.split_at_each(|x| *x == 0).rev().collect::<Vec<_>>(),
+    ///     vec![&[4][..], &[2, 3], &[1]],
+    /// );
+    ///
+    /// // Adjacent separators, and separators at the ends, produce empty subslices
+    /// assert_eq!(
+    ///     [0, 1, 0, 0].split_at_each(|x| *x == 0).collect::<Vec<_>>(),
+    ///     vec![&[][..], &[1], &[], &[]],
+    /// );
+    ///
+    /// ```
+    fn split_at_each<'a, P>(&'a self, pred: P) -> SplitAtEach<'a, Self::Elem, P>
+    where
+        P: FnMut(&Self::Elem) -> bool,
+    {
+        SplitAtEach {
+            slice: self.borrow(),
+            pred,
+            finished: false,
+        }
+    }
+
+    /// Returns an iterator over the subslices separated by elements 
+    /// for which `pred` returns true, 
+    /// with each separator at the end of the preceding subslice.
+    ///
+    /// This is like the `split_inclusive` method of slices,
+    /// which requires Rust 1.51.0.
+    ///
+    /// The returned type implements 
+    /// `DoubleEndedIterator<Item = &[Self::Elem]>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 0, 2, 3, 0, 4];
+    ///
+    /// assert_eq!(
+    ///     list.split_inclusive_at_each(|x| *x == 0).collect::<Vec<_>>(),
+    ///     vec![&[1, 0][..], &[2, 3, 0], &[4]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     [0, 1, 0].split_inclusive_at_each(|x| *x == 0).rev().collect::<Vec<_>>(),
+    ///     vec![&[1, 0][..], &[0]],
+    /// );
+    ///
+    /// assert_eq!(<[u8]>::split_inclusive_at_each(&[], |_| true).next(), None);
+    ///
+    /// ```
+    fn split_inclusive_at_each<'a, P>(&'a self, pred: P) -> SplitInclusiveAtEach<'a, Self::Elem, P>
+    where
+        P: FnMut(&Self::Elem) -> bool,
+    {
+        SplitInclusiveAtEach {
+            slice: self.borrow(),
+            pred,
+        }
+    }
+
+    /// Returns an iterator over the subslices of consecutive equal elements.
+    ///
+    /// This is like [`split_while`](#method.split_while) with the identity function,
+    /// but comparing the elements themselves, 
+    /// requiring neither `Clone` nor `Eq` for them.
+    ///
+    /// The returned type implements 
+    /// `DoubleEndedIterator<Item = &[Self::Elem]>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = [1, 1, 2, 3, 3, 3, 1];
+    ///
+    /// assert_eq!(
+    ///     list.group_runs().collect::<Vec<_>>(),
+    ///     vec![&[1, 1][..], &[2], &[3, 3, 3], &[1]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     list.group_runs().rev().collect::<Vec<_>>(),
+    ///     vec![&[1][..], &[3, 3, 3], &[2], &[1, 1]],
+    /// );
+    ///
+    /// // NaN isn't equal to itself, so each one is in its own run.
+    /// let floats = [0.5, 0.5, std::f64::NAN, std::f64::NAN];
+    /// assert_eq!(floats.group_runs().map(|run| run.len()).collect::<Vec<_>>(), vec![2, 1, 1]);
+    ///
+    /// ```
+    fn group_runs<'a>(&'a self) -> GroupRuns<'a, Self::Elem>
+    where
+        Self::Elem: PartialEq,
+    {
+        GroupRuns {
+            slice: self.borrow(),
+        }
+    }
+
+    /// Returns an iterator over the runs of consecutive equal elements,
+    /// yielding the first element of each run along with how long the run is.
+    ///
+    /// This is like [`group_runs`](#method.group_runs),
+    /// but yielding `(&run[0], run.len())` instead of the `run` subslices.
+    ///
+    /// The returned type implements 
+    /// `DoubleEndedIterator<Item = (&Self::Elem, usize)>`.
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use core_extensions::ValSliceExt;
-    /// use core_extensions::slices::KeySlice;
-    /// 
-    /// {
-    ///     let list = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
-    /// 
-    ///     assert_eq!(
-    ///         list.split_while(|x| x/4).collect::<Vec<_>>(),
-    ///         vec![
-    ///             KeySlice{key: 0, slice: &[0, 1, 2, 3]},
-    ///             KeySlice{key: 1, slice: &[4, 5, 6, 7]},
-    ///             KeySlice{key: 2, slice: &[8]},
-    ///         ]
-    ///     );
-    /// }
-    /// 
-    /// {
-    ///     let list = [0, 4, 1, 5, 9, 8, 7];
-    /// 
-    ///     assert_eq!(
-    ///         list.split_while(|x| x%4).collect::<Vec<_>>(),
-    ///         vec![
-    ///             KeySlice{key: 0, slice: &[0, 4]},
-    ///             KeySlice{key: 1, slice: &[1, 5, 9]},
-    ///             KeySlice{key: 0, slice: &[8]},
-    ///             KeySlice{key: 3, slice: &[7]},
-    ///         ]
-    ///     );
-    /// }
-    /// 
-    /// 
+    ///
+    /// let list = ['a', 'a', 'a', 'b', 'c', 'c', 'a'];
+    ///
+    /// assert_eq!(
+    ///     list.dedup_runs().collect::<Vec<_>>(),
+    ///     vec![(&'a', 3), (&'b', 1), (&'c', 2), (&'a', 1)],
+    /// );
+    ///
+    /// assert_eq!(list.dedup_runs().count(), 4);
+    ///
     /// ```
-    /// 
-    fn split_while<'a, P, U>(&'a self, mut mapper: P) -> SplitSliceWhile<'a, Self::Elem, P, U>
+    fn dedup_runs<'a>(&'a self) -> DedupRuns<'a, Self::Elem>
     where
-        P: FnMut(&'a Self::Elem) -> U,
-        U: Eq + Clone,
+        Self::Elem: PartialEq,
     {
-        let this:&'a [Self::Elem] = self.borrow();
-        SplitSliceWhile {
-            last_left: this.first().map(&mut mapper),
-            last_right: this.last().map(&mut mapper),
-            mapper,
-            s: this,
+        DedupRuns {
+            runs: self.group_runs(),
         }
     }
 
-    /// A variation of [`split_while`](#method.split_while) that iterates
-    /// from the right(the order of subslices is reversed).
-    /// 
-    /// The returned type implements 
-    /// `DoubleEndedIterator<Item =`[`KeySlice`](./struct.KeySlice.html)`<Self::Elem, U>>`.
-    /// 
+    /// Returns an iterator over `parts` subslices that cover the whole slice,
+    /// whose lengths differ by at most one.
+    ///
+    /// The longer subslices come first.
+    ///
+    /// This differs from `chunks` in that it fixes the amount of subslices,
+    /// rather than their length.
+    /// If `parts` is greater than `self.len()`, the trailing subslices are empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts` is zero.
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use core_extensions::ValSliceExt;
-    /// use core_extensions::slices::KeySlice;
-    /// 
-    /// {
-    ///     let list = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
-    /// 
-    ///     assert_eq!(
-    ///         list.rsplit_while(|x| x/4 ).collect::<Vec<_>>(),
-    ///         vec![
-    ///             KeySlice{key: 2, slice: &[8]},
-    ///             KeySlice{key: 1, slice: &[4, 5, 6, 7]},
-    ///             KeySlice{key: 0, slice: &[0, 1, 2, 3]},
-    ///         ]
-    ///     );
-    /// }
-    /// 
-    /// {
-    ///     let list = [0, 4, 1, 5, 9, 8, 7];
-    /// 
-    ///     assert_eq!(
-    ///         list.rsplit_while(|x| x%4 ).collect::<Vec<_>>(),
-    ///         vec![
-    ///             KeySlice{key: 3, slice: &[7]},
-    ///             KeySlice{key: 0, slice: &[8]},
-    ///             KeySlice{key: 1, slice: &[1, 5, 9]},
-    ///             KeySlice{key: 0, slice: &[0, 4]},
-    ///         ]
-    ///     );
-    /// }
-    /// 
-    /// 
-    /// 
+    ///
+    /// let list = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    ///
+    /// assert_eq!(
+    ///     list.split_into(3).collect::<Vec<_>>(),
+    ///     vec![&[1, 2, 3, 4][..], &[5, 6, 7], &[8, 9, 10]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     list.split_into(3).map(|s| s.len()).rev().collect::<Vec<_>>(),
+    ///     vec![3, 3, 4],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     list[..3].split_into(5).collect::<Vec<_>>(),
+    ///     vec![&[1][..], &[2], &[3], &[], &[]],
+    /// );
+    ///
+    /// assert_eq!(list.split_into(1).collect::<Vec<_>>(), vec![&list[..]]);
+    ///
     /// ```
-    /// 
-    fn rsplit_while<'a, P, U>(&'a self, mut mapper: P) -> RSplitSliceWhile<'a, Self::Elem, P, U>
-    where
-        P: FnMut(&'a Self::Elem) -> U,
-        U: Eq + Clone,
-    {
+    fn split_into<'a>(&'a self, parts: usize) -> SplitInto<'a, Self::Elem> {
+        assert!(parts != 0, "cannot split a slice into zero parts");
+        SplitInto {
+            slice: self.borrow(),
+            parts,
+        }
+    }
+
+    /// Returns an iterator over all the elements of the slice,
+    /// starting from the `start` index, and wrapping around to the start of the slice.
+    ///
+    /// `start` wraps around if it's greater than or equal to the length of the slice,
+    /// ie: `[0, 1, 2].iter_from(4)` is equivalent to `[0, 1, 2].iter_from(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::ValSliceExt;
+    ///
+    /// let list = ['a', 'b', 'c', 'd'];
+    ///
+    /// assert_eq!(list.iter_from(2).collect::<String>(), "cdab");
+    /// assert_eq!(list.iter_from(0).collect::<String>(), "abcd");
+    /// assert_eq!(list.iter_from(5).collect::<String>(), "bcda");
+    /// assert_eq!(list.iter_from(3).rev().collect::<String>(), "cbad");
+    ///
+    /// assert_eq!(<[u8]>::iter_from(&[], 3).next(), None);
+    ///
+    /// ```
+    fn iter_from<'a>(&'a self, start: usize) -> CircularIter<'a, Self::Elem> {
         let this: &'a [Self::Elem] = self.borrow();
-        RSplitSliceWhile {
-            last_left: this.first().map(&mut mapper),
-            last_right: this.last().map(&mut mapper),
-            mapper,
-            s: this,
+        let start = if this.is_empty() { 0 } else { start % this.len() };
+        let (before, after) = this.split_at(start);
+        CircularIter {
+            iter: after.iter().chain(before.iter()),
         }
     }
+
+    #[cfg(feature = "rust_1_51")]
+    val_slice_ext_const_generic_methods!{}
 }
 
 impl<This> ValSliceExt for This
@@ -341,6 +1233,58 @@ pub trait SliceExt {
     /// ```
     fn get_offset_of_slice(&self, other: &Self) -> Option<usize>;
 
+    /// Returns the range of indices that `other` occupies inside of `self`.
+    ///
+    /// This returns `Some(start..start + other.len())`, 
+    /// where `start` is what [`get_offset_of_slice`](#tymethod.get_offset_of_slice) returns,
+    /// so that `&self[range]` is the same slice as `other`.
+    ///
+    /// If `other` is a zero-length slice, or is not inside `self`, this returns `None`.
+    ///
+    /// This is like the unstable `subslice_range` method of slices,
+    /// with the trailing underscore to avoid name collisions.
+    ///
+    /// # Example
+    ///
+    /// ### Called on slices
+    ///
+    /// ```
+    /// use core_extensions::SliceExt;
+    ///
+    /// let list = [0, 1, 2, 3, 4, 5];
+    /// 
+    /// let other = [0, 1, 2, 3];
+    ///
+    /// assert_eq!(list.subslice_range_(&list[..0]), None);
+    /// assert_eq!(list.subslice_range_(&list[1..]), Some(1..6));
+    /// assert_eq!(list.subslice_range_(&list[2..4]), Some(2..4));
+    /// assert_eq!(list.subslice_range_(&list[6..]), None);
+    ///
+    /// assert_eq!(list.subslice_range_(&other), None);
+    ///
+    /// ```
+    ///
+    /// ### Called on `str`s
+    ///
+    /// ```
+    /// use core_extensions::SliceExt;
+    ///
+    /// let string = "foo bar baz";
+    /// 
+    /// let another = String::from(string);
+    /// 
+    /// let bar = string.split(' ').nth(1).unwrap();
+    /// 
+    /// let range = string.subslice_range_(bar).unwrap();
+    /// assert_eq!(range, 4..7);
+    /// assert_eq!(&string[range.end..], " baz");
+    ///
+    /// assert_eq!(string.subslice_range_(&string[11..]), None);
+    /// assert_eq!(string.subslice_range_(&another), None);
+    ///
+    /// ```
+    fn subslice_range_(&self, other: &Self) -> Option<Range<usize>>;
+
     /// Returns the index of `other` if it's stored in the slice (if it points within the slice).
     ///
     /// If `other` is not inside `self`, this returns `self.len()`.
@@ -441,6 +1385,10 @@ pub trait SliceExt {
 
     /// Used for non-panicking slicing.
     ///
+    /// `range` can be any kind of range of `usize`
+    /// (eg: `1..3`, `..3`, `1..`, `1..=3`, `..`),
+    /// an unbounded start is `0`, and an unbounded end is `self.len()`.
+    ///
     /// If `range.end` is less than `range.start`, this returns an empty slice.
     ///
     /// # `bias` parameter
@@ -464,6 +1412,12 @@ pub trait SliceExt {
     /// assert_eq!(arr.slice_lossy(3..1000, ()), &arr[3..]);
     /// assert_eq!(arr.slice_lossy(1000..1000, ()), &[]);
     /// assert_eq!(arr.slice_lossy(1000..0, ()), &[]);
+    ///
+    /// assert_eq!(arr.slice_lossy(..3, ()), &arr[..3]);
+    /// assert_eq!(arr.slice_lossy(4.., ()), &arr[4..]);
+    /// assert_eq!(arr.slice_lossy(1..=3, ()), &arr[1..4]);
+    /// assert_eq!(arr.slice_lossy(.., ()), &arr[..]);
+    /// assert_eq!(arr.slice_lossy(..1000, ()), &arr[..]);
     /// ```
     ///
     /// ### `str` slice
@@ -485,19 +1439,25 @@ pub trait SliceExt {
     /// assert_eq!(word.slice_lossy(1000..1000, ()), "");
     /// assert_eq!(word.slice_lossy(1000..1000, SliceBias::OUT), "");
     /// assert_eq!(word.slice_lossy(1000..0, SliceBias::OUT), "");
+    ///
+    /// assert_eq!(word.slice_lossy(..3, SliceBias::OUT), "niñ");
+    /// assert_eq!(word.slice_lossy(3.., SliceBias::IN), "o");
+    /// assert_eq!(word.slice_lossy(.., ()), "niño");
     /// ```
     ///
     /// [`SliceBias`]: struct.SliceBias.html
     ///
-    fn slice_lossy<SB>(&self, range: Range<usize>, bias: SB) -> &Self
+    fn slice_lossy<R, SB>(&self, range: R, bias: SB) -> &Self
     where
+        R: RangeBounds<usize>,
         SB: Into<SliceBias>;
 
     /// Used for non-panicking mutable slicing.
     ///
     /// Identical behavior to [`slice_lossy`](#tymethod.slice_lossy) with respect to ranges.
-    fn slice_lossy_mut<SB>(&mut self, range: Range<usize>, bias: SB) -> &mut Self
+    fn slice_lossy_mut<R, SB>(&mut self, range: R, bias: SB) -> &mut Self
     where
+        R: RangeBounds<usize>,
         SB: Into<SliceBias>;
 }
 
@@ -549,6 +1509,16 @@ macro_rules! impl_common_slice_extensions {($T:ident) => {
         }
     }
 
+    fn subslice_range_(&self,other:&Self)->Option<Range<usize>>{
+        let start = self.get_offset_of_slice(other)?;
+        let end = start + other.len();
+        if end <= self.len() {
+            Some(start..end)
+        } else {
+            None
+        }
+    }
+
     fn index_of(&self,other:*const $T)->usize{
         if mem::size_of::<$T>() == 0 {
             return if self.as_ptr() == other {
@@ -583,6 +1553,25 @@ macro_rules! impl_common_slice_extensions {($T:ident) => {
 
 }}
 
+// Converts `range` into a `Range`,
+// where an unbounded start is `0` and an unbounded end is `len`.
+fn bounds_to_range<R>(range: &R, len: usize) -> Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&x) => x,
+        Bound::Excluded(&x) => x.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&x) => x.saturating_add(1),
+        Bound::Excluded(&x) => x,
+        Bound::Unbounded => len,
+    };
+    start..end
+}
+
 mod str_impls {
     use super::*;
 
@@ -616,18 +1605,22 @@ mod str_impls {
     impl SliceExt for str {
         impl_common_slice_extensions! {u8}
 
-        fn slice_lossy<SB>(&self, range: Range<usize>, bias: SB) -> &Self
+        fn slice_lossy<R, SB>(&self, range: R, bias: SB) -> &Self
         where
+            R: RangeBounds<usize>,
             SB: Into<SliceBias>,
         {
-            &self[lossy_str_range(self, range, bias.into())]
+            let r = bounds_to_range(&range, self.len());
+            &self[lossy_str_range(self, r, bias.into())]
         }
 
-        fn slice_lossy_mut<SB>(&mut self, range: Range<usize>, bias: SB) -> &mut Self
+        fn slice_lossy_mut<R, SB>(&mut self, range: R, bias: SB) -> &mut Self
         where
+            R: RangeBounds<usize>,
             SB: Into<SliceBias>,
         {
-            let r = lossy_str_range(self, range, bias.into());
+            let r = bounds_to_range(&range, self.len());
+            let r = lossy_str_range(self, r, bias.into());
             &mut self[r]
         }
     }
@@ -646,17 +1639,89 @@ mod slice_impls {
     impl<T> SliceExt for [T] {
         impl_common_slice_extensions! {T}
 
-        fn slice_lossy<SB>(&self, range: Range<usize>, _bias: SB) -> &Self {
-            &self[lossy_range(self, range)]
+        fn slice_lossy<R, SB>(&self, range: R, _bias: SB) -> &Self
+        where
+            R: RangeBounds<usize>,
+        {
+            &self[lossy_range(self, bounds_to_range(&range, self.len()))]
         }
 
-        fn slice_lossy_mut<SB>(&mut self, range: Range<usize>, _bias: SB) -> &mut Self {
-            let r = lossy_range(self, range);
+        fn slice_lossy_mut<R, SB>(&mut self, range: R, _bias: SB) -> &mut Self
+        where
+            R: RangeBounds<usize>,
+        {
+            let r = bounds_to_range(&range, self.len());
+            let r = lossy_range(self, r);
             &mut self[r]
         }
     }
 }
 
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Removes the first element of the `slice` cursor, returning a reference to it.
+///
+/// This shrinks `slice` to exclude the returned element,
+/// returning `None` (and leaving `slice` unchanged) if it's empty.
+///
+/// This is equivalent to the unstable `<[T]>::take_first` method.
+///
+/// # Example
+///
+/// ```
+/// use core_extensions::slices::take_first;
+///
+/// let mut cursor: &[u32] = &[3, 5, 8];
+///
+/// assert_eq!(take_first(&mut cursor), Some(&3));
+/// assert_eq!(cursor, [5, 8]);
+///
+/// assert_eq!(take_first(&mut cursor), Some(&5));
+/// assert_eq!(take_first(&mut cursor), Some(&8));
+/// assert_eq!(cursor, []);
+///
+/// assert_eq!(take_first(&mut cursor), None);
+///
+/// ```
+pub fn take_first<'a, T>(slice: &mut &'a [T]) -> Option<&'a T> {
+    let (first, rest) = slice.split_first()?;
+    *slice = rest;
+    Some(first)
+}
+
+/// Removes the last element of the `slice` cursor, returning a reference to it.
+///
+/// This shrinks `slice` to exclude the returned element,
+/// returning `None` (and leaving `slice` unchanged) if it's empty.
+///
+/// This is equivalent to the unstable `<[T]>::take_last` method.
+///
+/// # Example
+///
+/// ```
+/// use core_extensions::slices::take_last;
+///
+/// let mut cursor: &[u32] = &[3, 5, 8];
+///
+/// assert_eq!(take_last(&mut cursor), Some(&8));
+/// assert_eq!(cursor, [3, 5]);
+///
+/// assert_eq!(take_last(&mut cursor), Some(&5));
+/// assert_eq!(take_last(&mut cursor), Some(&3));
+/// assert_eq!(cursor, []);
+///
+/// assert_eq!(take_last(&mut cursor), None);
+///
+/// ```
+pub fn take_last<'a, T>(slice: &mut &'a [T]) -> Option<&'a T> {
+    let (last, rest) = slice.split_last()?;
+    *slice = rest;
+    Some(last)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -841,6 +1906,49 @@ mod tests {
             assert_eq!(other.get_offset_of_slice(&slice_c), None);
         }
     }
+    #[test]
+    fn subslice_range_() {
+        fn inner<T>(list: &[T; 12]){
+            let slice_a = &list[0..4];
+            let slice_b = &list[4..8];
+
+            assert_eq!(list.subslice_range_(&list[..]), Some(0..12));
+            assert_eq!(list.subslice_range_(&slice_b[1..3]), Some(5..7));
+            assert_eq!(list.subslice_range_(&list[11..]), Some(11..12));
+            assert_eq!(list.subslice_range_(&list[12..]), None);
+
+            assert_eq!(slice_b.subslice_range_(&slice_a[3..]), None);
+            assert_eq!(slice_b.subslice_range_(&slice_b[1..1]), None);
+            assert_eq!(slice_b.subslice_range_(&slice_b[1..]), Some(1..4));
+            assert_eq!(slice_b.subslice_range_(&list[3..6]), None);
+            assert_eq!(slice_b.subslice_range_(&list[6..9]), None);
+            assert_eq!(slice_b.subslice_range_(list), None);
+
+            let range = list.subslice_range_(&slice_b[2..]).unwrap();
+            assert!(list[range].is_slice(&slice_b[2..]));
+        }
+
+        inner(&[0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+        inner(&[0u32, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+        inner(&[""; 12]);
+
+        {
+            let list = [(); 12];
+            let slice_b = &list[4..8];
+            let other = Unpromoted([(); 12]);
+
+            assert_eq!(slice_b.subslice_range_(&slice_b[1..]), Some(0..3));
+            assert_eq!(slice_b.subslice_range_(&list[..4]), Some(0..4));
+            assert_eq!(slice_b.subslice_range_(&list[..5]), None);
+            assert_eq!(list.subslice_range_(&*other), None);
+        }
+
+        let string = "foo bar baz";
+        assert_eq!(string.subslice_range_(&string[4..7]), Some(4..7));
+        assert_eq!(string[..7].subslice_range_(&string[4..]), None);
+        assert_eq!(string.subslice_range_(&string[4..4]), None);
+    }
+
     #[test]
     fn index_of() {
         fn inner<T>(list: &[T; 12]){
@@ -943,6 +2051,257 @@ mod tests {
             assert_eq!(other.get_index_of(&slice_c[0]), None);
         }
     }
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn split_into() {
+        let list = (0..20).collect::<Vec<u32>>();
+
+        for len in 0..list.len() {
+            let list = &list[..len];
+            for parts in 1..25 {
+                let chunks = list.split_into(parts).collect::<Vec<_>>();
+                let mut rev_chunks = list.split_into(parts).rev().collect::<Vec<_>>();
+                rev_chunks.reverse();
+
+                assert_eq!(chunks, rev_chunks);
+                assert_eq!(chunks.len(), parts);
+                assert_eq!(chunks.concat(), list);
+
+                let min = chunks.iter().map(|c| c.len()).min().unwrap();
+                let max = chunks.iter().map(|c| c.len()).max().unwrap();
+                assert!(max - min <= 1, "{:?}", chunks);
+            }
+        }
+
+        let mut iter = list[..10].split_into(3);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(&[7, 8, 9][..]));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(&[0, 1, 2, 3][..]));
+        assert_eq!(iter.next_back(), Some(&[4, 5, 6][..]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn take_first_last() {
+        let list = [0, 1, 2, 3, 4, 5, 6];
+
+        let mut cursor = &list[..];
+        for i in 0..7 {
+            assert_eq!(take_first(&mut cursor), Some(&i));
+            assert_eq!(cursor, &list[i + 1..]);
+        }
+        assert_eq!(take_first(&mut cursor), None);
+        assert!(cursor.is_empty());
+
+        let mut cursor = &list[..];
+        for i in (0..7).rev() {
+            assert_eq!(take_last(&mut cursor), Some(&i));
+            assert_eq!(cursor, &list[..i]);
+        }
+        assert_eq!(take_last(&mut cursor), None);
+        assert!(cursor.is_empty());
+
+        let mut cursor = &list[..];
+        assert_eq!(take_first(&mut cursor), Some(&0));
+        assert_eq!(take_last(&mut cursor), Some(&6));
+        assert_eq!(take_first(&mut cursor), Some(&1));
+        assert_eq!(take_last(&mut cursor), Some(&5));
+        assert_eq!(cursor, [2, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn split_at_each() {
+        let is_zero = |x: &u8| *x == 0;
+
+        let list = [1, 0, 2, 3, 0, 4];
+        let expected: [&[u8]; 3] = [&[1], &[2, 3], &[4]];
+        assert_eq!(list.split_at_each(is_zero).collect::<Vec<_>>(), expected);
+        assert_eq!(
+            list.split_at_each(is_zero).rev().collect::<Vec<_>>(),
+            expected.iter().rev().cloned().collect::<Vec<_>>(),
+        );
+
+        let mut iter = list.split_at_each(is_zero);
+        assert_eq!(iter.next(), Some(&[1][..]));
+        assert_eq!(iter.next_back(), Some(&[4][..]));
+        assert_eq!(iter.next_back(), Some(&[2, 3][..]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.split_at_each(is_zero).collect::<Vec<_>>(), [&[][..]]);
+        assert_eq!(
+            [0u8].split_at_each(is_zero).collect::<Vec<_>>(),
+            [&[][..], &[][..]],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn group_runs() {
+        let list = [0, 0, 1, 2, 2, 2, 0, 3];
+        let expected: [&[u8]; 5] = [&[0, 0], &[1], &[2, 2, 2], &[0], &[3]];
+        assert_eq!(list.group_runs().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            list.group_runs().rev().collect::<Vec<_>>(),
+            expected.iter().rev().cloned().collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            list.dedup_runs().collect::<Vec<_>>(),
+            vec![(&0, 2), (&1, 1), (&2, 3), (&0, 1), (&3, 1)],
+        );
+        assert_eq!(
+            list.dedup_runs().rev().collect::<Vec<_>>(),
+            vec![(&3, 1), (&0, 1), (&2, 3), (&1, 1), (&0, 2)],
+        );
+
+        let mut iter = list.group_runs();
+        assert_eq!(iter.size_hint(), (1, Some(8)));
+        assert_eq!(iter.next(), Some(&[0, 0][..]));
+        assert_eq!(iter.next_back(), Some(&[3][..]));
+        assert_eq!(iter.as_slice(), &[1, 2, 2, 2, 0]);
+        assert_eq!(iter.next_back(), Some(&[0][..]));
+        assert_eq!(iter.next(), Some(&[1][..]));
+        assert_eq!(iter.next_back(), Some(&[2, 2, 2][..]));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let single = [5; 4];
+        assert_eq!(single.group_runs().collect::<Vec<_>>(), vec![&single[..]]);
+        assert_eq!(single.dedup_runs().collect::<Vec<_>>(), vec![(&5, 4)]);
+
+        // Elements that aren't equal to themselves, like NaN
+        struct NeverEq;
+        impl PartialEq for NeverEq {
+            fn eq(&self, _: &Self) -> bool {
+                false
+            }
+        }
+        let never_eq = [NeverEq, NeverEq, NeverEq];
+        assert_eq!(never_eq.group_runs().map(|x| x.len()).collect::<Vec<_>>(), vec![1, 1, 1]);
+        assert_eq!(never_eq.group_runs().rev().map(|x| x.len()).collect::<Vec<_>>(), vec![1, 1, 1]);
+
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.group_runs().next(), None);
+        assert_eq!(empty.group_runs().next_back(), None);
+        assert_eq!(empty.dedup_runs().next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn split_inclusive_at_each() {
+        let is_zero = |x: &u8| *x == 0;
+
+        let list = [1, 0, 2, 3, 0, 4];
+        let expected: [&[u8]; 3] = [&[1, 0], &[2, 3, 0], &[4]];
+        assert_eq!(list.split_inclusive_at_each(is_zero).collect::<Vec<_>>(), expected);
+        assert_eq!(
+            list.split_inclusive_at_each(is_zero).rev().collect::<Vec<_>>(),
+            expected.iter().rev().cloned().collect::<Vec<_>>(),
+        );
+
+        let list = [0, 0, 1, 0];
+        let expected: [&[u8]; 3] = [&[0], &[0], &[1, 0]];
+        assert_eq!(list.split_inclusive_at_each(is_zero).collect::<Vec<_>>(), expected);
+        assert_eq!(
+            list.split_inclusive_at_each(is_zero).rev().collect::<Vec<_>>(),
+            expected.iter().rev().cloned().collect::<Vec<_>>(),
+        );
+
+        let mut iter = [1, 0, 2, 0, 3].split_inclusive_at_each(is_zero);
+        assert_eq!(iter.next_back(), Some(&[3][..]));
+        assert_eq!(iter.next(), Some(&[1, 0][..]));
+        assert_eq!(iter.next_back(), Some(&[2, 0][..]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn find_subslice() {
+        let list = [1, 2, 3, 4];
+        assert_eq!(list.find_subslice(&[2, 3]), Some(1));
+        assert_eq!(list.rfind_subslice(&[2, 3]), Some(1));
+        assert_eq!(list.find_subslice(&[4]), Some(3));
+        assert_eq!(list.rfind_subslice(&[1]), Some(0));
+        assert_eq!(list.find_subslice(&[2, 4]), None);
+        assert_eq!(list.rfind_subslice(&[2, 4]), None);
+        assert_eq!(list.find_subslice(&[]), Some(0));
+        assert_eq!(list.rfind_subslice(&[]), Some(4));
+
+        let repeated = [0, 0, 0];
+        assert_eq!(repeated.find_subslice(&[0, 0]), Some(0));
+        assert_eq!(repeated.rfind_subslice(&[0, 0]), Some(1));
+
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.find_subslice(&[]), Some(0));
+        assert_eq!(empty.rfind_subslice(&[]), Some(0));
+        assert_eq!(empty.find_subslice(&[0]), None);
+        assert_eq!(empty.rfind_subslice(&[0]), None);
+    }
+
+    #[test]
+    #[cfg(feature = "rust_1_51")]
+    fn get_chunks() {
+        let mut list = [0, 1, 2, 3, 4];
+
+        assert_eq!(list.get_first_chunk::<0>(), Some((&[], &list[..])));
+        assert_eq!(list.get_last_chunk::<0>(), Some((&list[..], &[])));
+        assert_eq!(list.get_first_chunk::<3>(), Some((&[0, 1, 2], &list[3..])));
+        assert_eq!(list.get_last_chunk::<3>(), Some((&list[..2], &[2, 3, 4])));
+        assert_eq!(list.get_first_chunk::<5>(), Some((&[0, 1, 2, 3, 4], &[][..])));
+        assert_eq!(list.get_last_chunk::<5>(), Some((&[][..], &[0, 1, 2, 3, 4])));
+        assert_eq!(list.get_first_chunk::<6>(), None);
+        assert_eq!(list.get_last_chunk::<6>(), None);
+
+        {
+            let (chunk, rest) = list.get_first_chunk_mut::<2>().unwrap();
+            assert_eq!(rest, &mut [2, 3, 4]);
+            *chunk = [10, 11];
+        }
+        {
+            let (rest, chunk) = list.get_last_chunk_mut::<2>().unwrap();
+            assert_eq!(rest, &mut [10, 11, 2]);
+            *chunk = [13, 14];
+        }
+        assert_eq!(list, [10, 11, 2, 13, 14]);
+        assert_eq!(list.get_first_chunk_mut::<6>(), None);
+        assert_eq!(list.get_last_chunk_mut::<6>(), None);
+
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.get_first_chunk::<0>(), Some((&[], &[][..])));
+        assert_eq!(empty.get_last_chunk::<1>(), None);
+    }
+
+    #[test]
+    fn is_sorted() {
+        let empty: [u8; 0] = [];
+        assert!(empty.is_sorted_());
+        assert!(empty.is_sorted_by(|_, _| false));
+        assert!([3].is_sorted_());
+        assert!([3].is_sorted_by(|_, _| false));
+
+        assert!([1, 2].is_sorted_());
+        assert!([1, 1, 1].is_sorted_());
+        assert!([1, 2, 3, 3, 8].is_sorted_());
+        assert!(![2, 1].is_sorted_());
+        assert!(![1, 2, 3, 0].is_sorted_());
+
+        let descending = |l: &u32, r: &u32| l >= r;
+        assert!([8, 3, 3, 2, 1].is_sorted_by(descending));
+        assert!(![8, 3, 4, 2, 1].is_sorted_by(descending));
+        assert!(![1, 2, 3].is_sorted_by(descending));
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_into_zero_parts() {
+        let _ = [1, 2, 3].split_into(0);
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn slice_lossy_slice_examples() {
@@ -1037,6 +2396,48 @@ mod tests {
         assert_eq!(sub_word, "ño");
     }
 
+    #[test]
+    fn slice_lossy_range_bounds() {
+        use std_::ops::Bound;
+
+        let list = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        assert!(list.slice_lossy(.., ()).is_slice(&list[..]));
+        assert!(list.slice_lossy(..4, ()).is_slice(&list[..4]));
+        assert!(list.slice_lossy(..=4, ()).is_slice(&list[..5]));
+        assert!(list.slice_lossy(..1000, ()).is_slice(&list[..]));
+        assert!(list.slice_lossy(..=!0, ()).is_slice(&list[..]));
+        assert!(list.slice_lossy(4.., ()).is_slice(&list[4..]));
+        assert!(list.slice_lossy(1000.., ()).is_slice(&list[10..]));
+        assert!(list.slice_lossy(2..=5, ()).is_slice(&list[2..6]));
+        let (start, end) = (6, 2);
+        assert!(list.slice_lossy(start..=end, ()).is_empty());
+        assert!(list
+            .slice_lossy((Bound::Excluded(2), Bound::Excluded(5)), ())
+            .is_slice(&list[3..5]));
+        assert!(list
+            .slice_lossy((Bound::Excluded(!0), Bound::Unbounded), ())
+            .is_slice(&list[10..]));
+
+        let mut list_mut = list;
+        list_mut.slice_lossy_mut(7.., ())[0] = 100;
+        list_mut.slice_lossy_mut(..=1, ())[1] = 101;
+        assert_eq!(list_mut, [0, 101, 2, 3, 4, 5, 6, 100, 8, 9]);
+
+        let word = "niño";
+        assert!(word.slice_lossy(.., ()).is_slice(word));
+        assert!(word.slice_lossy(..3, SliceBias::IN).is_slice(&word[..2]));
+        assert!(word.slice_lossy(..=2, SliceBias::OUT).is_slice(&word[..4]));
+        assert!(word.slice_lossy(3.., SliceBias::OUT).is_slice(&word[2..]));
+        assert!(word.slice_lossy(3.., SliceBias::IN).is_slice(&word[4..]));
+        assert!(word.slice_lossy(1000.., ()).is_slice(&word[5..]));
+
+        let mut bytes = [0u8; 5];
+        bytes.copy_from_slice("niño".as_bytes());
+        let word_mut = std_::str::from_utf8_mut(&mut bytes).unwrap();
+        word_mut.slice_lossy_mut(3.., SliceBias::OUT).make_ascii_uppercase();
+        assert_eq!(word_mut, "niñO");
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     // Too slow to run in miri, and there's no unsafe code here.
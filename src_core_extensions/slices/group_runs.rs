@@ -0,0 +1,78 @@
+/// Iterator over the subslices of consecutive equal elements of a slice.
+///
+/// Look [here](trait.ValSliceExt.html#method.group_runs) for examples.
+#[derive(Debug, Clone)]
+pub struct GroupRuns<'a, T: 'a> {
+    pub(super) slice: &'a [T],
+}
+
+impl<'a, T: PartialEq> Iterator for GroupRuns<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        let (first, rest) = self.slice.split_first()?;
+        // Searching from the second element,
+        // so that elements that aren't equal to themselves (eg: NaN) are still yielded.
+        let end = rest
+            .iter()
+            .position(|x| x != first)
+            .map_or(self.slice.len(), |i| i + 1);
+        let (ret, rem) = self.slice.split_at(end);
+        self.slice = rem;
+        Some(ret)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let min_len = if self.slice.is_empty() { 0 } else { 1 };
+        (min_len, Some(self.slice.len()))
+    }
+}
+
+impl<'a, T: PartialEq> DoubleEndedIterator for GroupRuns<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        let (last, rest) = self.slice.split_last()?;
+        let start = rest
+            .iter()
+            .rposition(|x| x != last)
+            .map_or(0, |i| i + 1);
+        let (rem, ret) = self.slice.split_at(start);
+        self.slice = rem;
+        Some(ret)
+    }
+}
+
+impl<'a, T> GroupRuns<'a, T> {
+    /// Returns the rest of the slice that hasn't been grouped yet.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+}
+
+//-------------------------------------------------------------------------------------------
+
+/// Iterator over the runs of consecutive equal elements of a slice,
+/// yielding the first element of each run along with the length of the run.
+///
+/// Look [here](trait.ValSliceExt.html#method.dedup_runs) for examples.
+#[derive(Debug, Clone)]
+pub struct DedupRuns<'a, T: 'a> {
+    pub(super) runs: GroupRuns<'a, T>,
+}
+
+impl<'a, T: PartialEq> Iterator for DedupRuns<'a, T> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<(&'a T, usize)> {
+        self.runs.next().map(|run| (&run[0], run.len()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.runs.size_hint()
+    }
+}
+
+impl<'a, T: PartialEq> DoubleEndedIterator for DedupRuns<'a, T> {
+    fn next_back(&mut self) -> Option<(&'a T, usize)> {
+        self.runs.next_back().map(|run| (&run[0], run.len()))
+    }
+}
@@ -1,9 +1,24 @@
 //! Slice extension traits, and related items.
 
+mod adjacent_pairs;
+mod circular_iter;
 mod extensions;
+mod group_runs;
+#[cfg(feature = "rust_1_51")]
+mod array_iters;
 mod slice_bias;
 mod slice_split_while;
-pub use self::extensions::{ValSliceExt,SliceExt};
+mod split_at_each;
+mod split_into;
+pub use self::adjacent_pairs::AdjacentPairs;
+pub use self::circular_iter::CircularIter;
+pub use self::extensions::{ValSliceExt,SliceExt,take_first,take_last};
+pub use self::group_runs::{DedupRuns, GroupRuns};
+#[cfg(feature = "rust_1_51")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+pub use self::array_iters::{ArrayChunks, WindowsMap};
 pub use self::slice_bias::BiasDirection;
 pub use self::slice_bias::SliceBias;
-pub use self::slice_split_while::{KeySlice,SplitSliceWhile,RSplitSliceWhile};
+pub use self::slice_split_while::{ChunksWhile,KeySlice,SplitSliceWhile,RSplitSliceWhile};
+pub use self::split_at_each::{SplitAtEach, SplitInclusiveAtEach};
+pub use self::split_into::SplitInto;
@@ -1,4 +1,5 @@
 use std_::mem;
+use std_::ops::Range;
 
 #[allow(unused_imports)]
 use super::ValSliceExt;
@@ -154,6 +155,48 @@ where
 
 //-------------------------------------------------------------------------------------------
 
+/// Iterator over slices,
+/// in which all the elements in each slice were mapped to the same key by a closure,
+/// along with the range of each slice within the iterated-over slice.
+///
+/// Look [here](trait.ValSliceExt.html#method.chunks_while) for examples.
+#[derive(Debug, Clone)]
+pub struct ChunksWhile<'a, T: 'a, P, U> {
+    pub(super) iter: SplitSliceWhile<'a, T, P, U>,
+    pub(super) start: usize,
+    pub(super) end: usize,
+}
+
+impl<'a, T, P, U: Eq + Clone> Iterator for ChunksWhile<'a, T, P, U>
+where
+    P: FnMut(&'a T) -> U,
+{
+    type Item = (Range<usize>, KeySlice<'a, T, U>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let ks = self.iter.next()?;
+        let start = self.start;
+        self.start += ks.slice.len();
+        Some((start..self.start, ks))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, P, U: Eq + Clone> DoubleEndedIterator for ChunksWhile<'a, T, P, U>
+where
+    P: FnMut(&'a T) -> U,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ks = self.iter.next_back()?;
+        let end = self.end;
+        self.end -= ks.slice.len();
+        Some((self.end..end, ks))
+    }
+}
+
+//-------------------------------------------------------------------------------------------
+
 
 #[cfg(test)]
 #[cfg(feature = "alloc")]
@@ -281,6 +324,38 @@ mod test{
         }
     }
 
+    #[test]
+    fn chunks_while(){
+        for list in &[new_list_0(),new_list_1(),new_list_2()] {
+            for &mapper in &[mapper_0 as fn(&u32)->u32, mapper_2, |_: &u32| 0, |x: &u32| *x] {
+                let chunks=list.chunks_while(mapper).collect::<Vec<_>>();
+                let splits=list.split_while(mapper).collect::<Vec<_>>();
+                assert_eq!(chunks.iter().map(|x| x.1.clone() ).collect::<Vec<_>>(), splits);
+                
+                let mut end=0;
+                for (range, ks) in &chunks {
+                    assert!(!ks.slice.is_empty());
+                    assert_eq!(range.start, end);
+                    assert_eq!(&list[range.clone()], ks.slice);
+                    end=range.end;
+                }
+                assert_eq!(end, list.len());
+
+                let mut rev_chunks=list.chunks_while(mapper).rev().collect::<Vec<_>>();
+                rev_chunks.reverse();
+                assert_eq!(rev_chunks, chunks);
+            }
+        }
 
+        let list=[0, 0, 1, 1, 2, 2, 3];
+        let mut iter=list.chunks_while(|x| *x);
+        assert_eq!(iter.next().map(|x| x.0), Some(0..2));
+        assert_eq!(iter.next_back().map(|x| x.0), Some(6..7));
+        assert_eq!(iter.next().map(|x| x.0), Some(2..4));
+        assert_eq!(iter.next_back().map(|x| x.0), Some(4..6));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
 
-}
\ No newline at end of file
+        assert_eq!(<[u8]>::chunks_while(&[], |x| *x).next(), None);
+    }
+}
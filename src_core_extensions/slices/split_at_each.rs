@@ -0,0 +1,116 @@
+/// Iterator over the subslices separated by elements that match a predicate,
+/// not including the separators.
+///
+/// Look [here](trait.ValSliceExt.html#method.split_at_each) for examples.
+#[derive(Debug, Clone)]
+pub struct SplitAtEach<'a, T: 'a, P> {
+    pub(super) slice: &'a [T],
+    pub(super) pred: P,
+    pub(super) finished: bool,
+}
+
+impl<'a, T, P> Iterator for SplitAtEach<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            return None;
+        }
+        match self.slice.iter().position(&mut self.pred) {
+            Some(i) => {
+                let ret = &self.slice[..i];
+                self.slice = &self.slice[i + 1..];
+                Some(ret)
+            }
+            None => {
+                self.finished = true;
+                Some(self.slice)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.slice.len() + 1))
+        }
+    }
+}
+
+impl<'a, T, P> DoubleEndedIterator for SplitAtEach<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            return None;
+        }
+        match self.slice.iter().rposition(&mut self.pred) {
+            Some(i) => {
+                let ret = &self.slice[i + 1..];
+                self.slice = &self.slice[..i];
+                Some(ret)
+            }
+            None => {
+                self.finished = true;
+                Some(self.slice)
+            }
+        }
+    }
+}
+
+/// Iterator over the subslices separated by elements that match a predicate,
+/// with each separator at the end of the preceding subslice.
+///
+/// Look [here](trait.ValSliceExt.html#method.split_inclusive_at_each) for examples.
+#[derive(Debug, Clone)]
+pub struct SplitInclusiveAtEach<'a, T: 'a, P> {
+    pub(super) slice: &'a [T],
+    pub(super) pred: P,
+}
+
+impl<'a, T, P> Iterator for SplitInclusiveAtEach<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let end = self.slice
+            .iter()
+            .position(&mut self.pred)
+            .map_or(self.slice.len(), |i| i + 1);
+        let (ret, rem) = self.slice.split_at(end);
+        self.slice = rem;
+        Some(ret)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.slice.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, Some(self.slice.len()))
+        }
+    }
+}
+
+impl<'a, T, P> DoubleEndedIterator for SplitInclusiveAtEach<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        // The last element is part of the last subslice whether it's a separator or not.
+        let (_, init) = self.slice.split_last()?;
+        let start = init.iter().rposition(&mut self.pred).map_or(0, |i| i + 1);
+        let (rem, ret) = self.slice.split_at(start);
+        self.slice = rem;
+        Some(ret)
+    }
+}
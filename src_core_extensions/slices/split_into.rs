@@ -0,0 +1,47 @@
+/// Iterator over a fixed amount of subslices of a slice,
+/// whose lengths differ by at most one.
+///
+/// Look [here](trait.ValSliceExt.html#method.split_into) for examples.
+#[derive(Debug, Clone)]
+pub struct SplitInto<'a, T: 'a> {
+    pub(super) slice: &'a [T],
+    pub(super) parts: usize,
+}
+
+impl<'a, T> Iterator for SplitInto<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.parts == 0 {
+            return None;
+        }
+        // Rounding up, so that the longer subslices come first.
+        let len = self.slice.len() / self.parts;
+        let len = if len * self.parts == self.slice.len() { len } else { len + 1 };
+        let (ret, rem) = self.slice.split_at(len);
+        self.slice = rem;
+        self.parts -= 1;
+        Some(ret)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.parts, Some(self.parts))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for SplitInto<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.parts == 0 {
+            return None;
+        }
+        // Rounding down, so that the shorter subslices come last.
+        let len = self.slice.len() / self.parts;
+        let (rem, ret) = self.slice.split_at(self.slice.len() - len);
+        self.slice = rem;
+        self.parts -= 1;
+        Some(ret)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for SplitInto<'a, T> {}
@@ -0,0 +1,182 @@
+use std_::{cmp, ops::Range};
+
+use alloc::vec::Vec;
+
+/// A string which caches its char count,
+/// and the byte offsets of its chars once it's indexed by char.
+///
+/// Constructing a `CharCounted` takes `O(n)` time to count the chars,
+/// where `n` is the length of the string.
+///
+/// The first call to a method that indexes by char position 
+/// ([`nth_char_index`], [`get_nth_char_index`], [`nth_char`], or [`char_range`])
+/// takes `O(n)` time and allocates a `Vec` with one `usize` offset per char
+/// (plus one for the end of the string).
+/// After that, those methods take `O(1)` time.
+///
+/// Prefer the [`StringExt`] methods when the string is only indexed by char a few times,
+/// since they don't allocate.
+///
+/// This is constructed with [`StringExt::char_counted`], or [`CharCounted::new`].
+///
+/// [`nth_char_index`]: #method.nth_char_index
+/// [`get_nth_char_index`]: #method.get_nth_char_index
+/// [`nth_char`]: #method.nth_char
+/// [`char_range`]: #method.char_range
+/// [`StringExt`]: ../trait.StringExt.html
+/// [`StringExt::char_counted`]: ../trait.StringExt.html#method.char_counted
+/// [`CharCounted::new`]: #method.new
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::strings::CharCounted;
+///
+/// let mut word = CharCounted::new("fooпозволяющий");
+///
+/// assert_eq!(word.char_count(), 14);
+/// assert_eq!(word.byte_len(), 25);
+///
+/// assert_eq!(word.nth_char_index(3), 3);
+/// assert_eq!(word.nth_char_index(4), 5);
+/// assert_eq!(word.nth_char_index(14), 25);
+///
+/// assert_eq!(word.nth_char(4), Some('о'));
+/// assert_eq!(word.char_range(1..5), "ooпо");
+///
+/// ```
+#[derive(Debug, Clone)]
+pub struct CharCounted<'a> {
+    string: &'a str,
+    char_count: usize,
+    // The byte offsets of every char, followed by the length of the string.
+    // Empty until the string is first indexed by char.
+    offsets: Vec<usize>,
+}
+
+impl<'a> CharCounted<'a> {
+    /// Constructs a `CharCounted`, counting the chars in `string`.
+    pub fn new(string: &'a str) -> Self {
+        Self {
+            string,
+            char_count: string.chars().count(),
+            offsets: Vec::new(),
+        }
+    }
+
+    /// Gets the wrapped string.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.string
+    }
+
+    /// The amount of chars in the string.
+    #[inline]
+    pub fn char_count(&self) -> usize {
+        self.char_count
+    }
+
+    /// The length of the string in bytes.
+    #[inline]
+    pub fn byte_len(&self) -> usize {
+        self.string.len()
+    }
+
+    fn offsets(&mut self) -> &[usize] {
+        if self.offsets.is_empty() {
+            let len = self.string.len();
+            self.offsets.reserve_exact(self.char_count + 1);
+            self.offsets.extend(self.string.char_indices().map(|(i, _)| i));
+            self.offsets.push(len);
+        }
+        &self.offsets
+    }
+
+    /// The byte index of the `nth` char,
+    /// returning `None` if there is no `nth` char.
+    pub fn get_nth_char_index(&mut self, nth: usize) -> Option<usize> {
+        if nth < self.char_count {
+            Some(self.offsets()[nth])
+        } else {
+            None
+        }
+    }
+
+    /// The byte index of the `nth` char,
+    /// returning the length of the string if there is no `nth` char.
+    pub fn nth_char_index(&mut self, nth: usize) -> usize {
+        self.get_nth_char_index(nth).unwrap_or(self.string.len())
+    }
+
+    /// Returns the `nth` char of the string.
+    pub fn nth_char(&mut self, nth: usize) -> Option<char> {
+        let string = self.string;
+        self.get_nth_char_index(nth)
+            .and_then(|i| string[i..].chars().next())
+    }
+
+    /// Gets the chars in the `range` range of char positions.
+    ///
+    /// Out of bounds positions are clamped to the amount of chars.
+    pub fn char_range(&mut self, range: Range<usize>) -> &'a str {
+        let end = self.nth_char_index(range.end);
+        let start = cmp::min(self.nth_char_index(range.start), end);
+        &self.string[start..end]
+    }
+}
+
+// The offsets are a lazily built cache, so they're not compared.
+impl<'a, 'b> PartialEq<CharCounted<'b>> for CharCounted<'a> {
+    #[inline]
+    fn eq(&self, other: &CharCounted<'b>) -> bool {
+        self.string == other.string
+    }
+}
+
+impl<'a> Eq for CharCounted<'a> {}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use strings::StringExt;
+
+    #[test]
+    fn matches_string_ext() {
+        for &text in &["", "foo", "débuter", "fooпозволяющий", "速度惊人"] {
+            let mut counted = CharCounted::new(text);
+            let count = text.chars().count();
+            assert_eq!(counted.char_count(), count);
+            assert_eq!(counted.byte_len(), text.len());
+            assert_eq!(counted.as_str(), text);
+
+            for n in 0..count + 3 {
+                assert_eq!(counted.get_nth_char_index(n), text.get_nth_char_index(n));
+                assert_eq!(counted.nth_char_index(n), text.nth_char_index(n));
+                assert_eq!(counted.nth_char(n), text.nth_char(n));
+                assert_eq!(counted.char_range(0..n), text.first_chars(n));
+                assert_eq!(counted.char_range(n..count + 3), text.from_nth_char(n));
+            }
+        }
+    }
+
+    #[test]
+    fn equality_ignores_offsets() {
+        let mut indexed = CharCounted::new("fooпозволяющий");
+        let unindexed = CharCounted::new("fooпозволяющий");
+        assert_eq!(indexed.nth_char(4), Some('о'));
+
+        assert_eq!(indexed, unindexed);
+        assert_eq!(unindexed, indexed);
+        assert_ne!(indexed, CharCounted::new("fooпозволяющ"));
+    }
+
+    #[test]
+    fn reversed_range() {
+        let mut counted = CharCounted::new("速度惊人");
+        for &(start, end) in &[(3, 1), (4, 0), (10, 1)] {
+            assert_eq!(counted.char_range(start..end), "");
+        }
+    }
+}
@@ -196,3 +196,115 @@ impl<'a> CharIndicesFrom<'a> {
         self.iter.as_str()
     }
 }
+
+//-------------------------------------------------------------------------------------------
+
+/// Iterator over the lines of a string wrapped to a maximum width in chars,
+/// breaking on ASCII whitespace.
+///
+/// Look [here](trait.StringExt.html#method.wrap_words) for examples.
+#[derive(Debug, Clone)]
+pub struct WrapWords<'a> {
+    pub(super) s: &'a str,
+    pub(super) width: usize,
+}
+
+impl<'a> Iterator for WrapWords<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let s = self.s.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        if s.is_empty() {
+            self.s = s;
+            return None;
+        }
+
+        // The end of the last word that fits in the line
+        let mut word_end = None::<usize>;
+        let mut prev_ws = false;
+        let mut end = None::<usize>;
+
+        for (count, (i, c)) in s.char_indices().enumerate() {
+            let is_ws = c.is_ascii_whitespace();
+            if is_ws && !prev_ws {
+                word_end = Some(i);
+            }
+            if count == self.width {
+                // Hard-breaking words that don't fit in a line by themselves.
+                end = Some(word_end.unwrap_or(i));
+                break;
+            }
+            prev_ws = is_ws;
+        }
+
+        let end = match (end, word_end) {
+            (Some(end), _) => end,
+            (None, Some(word_end)) if prev_ws => word_end,
+            (None, _) => s.len(),
+        };
+
+        let (ret, rem) = s.split_at(end);
+        self.s = rem;
+        Some(ret)
+    }
+}
+
+impl<'a> WrapWords<'a> {
+    /// Returns the rest of the string that hasn't been wrapped yet.
+    pub fn as_str(&self) -> &'a str {
+        self.s
+    }
+}
+
+//-------------------------------------------------------------------------------------------
+
+/// Like [`CharIndices`], but iterating from the end of the string.
+///
+/// The byte indices are the same as those of [`CharIndices`], 
+/// the position of the start of each char.
+///
+/// Look [here](trait.StringExt.html#method.char_indices_rev) for examples.
+/// 
+/// [`CharIndices`]: https://doc.rust-lang.org/std/str/struct.CharIndices.html
+#[derive(Clone, Debug)]
+pub struct CharIndicesRev<'a> {
+    pub(super) iter: CharIndices<'a>,
+}
+
+impl<'a> Iterator for CharIndicesRev<'a> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<(usize, char)> {
+        self.iter.next()
+    }
+}
+
+impl<'a> DoubleEndedIterator for CharIndicesRev<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, char)> {
+        self.iter.next()
+    }
+}
+
+impl<'a> CharIndicesRev<'a> {
+    /// Returns the rest of the slice to be iterated over.
+    pub fn as_str(&self) -> &'a str {
+        self.iter.as_str()
+    }
+}
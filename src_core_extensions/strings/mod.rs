@@ -3,14 +3,29 @@
 use std_::borrow::Borrow;
 use std_::cmp;
 use std_::fmt;
-use std_::str::CharIndices;
+use std_::iter::FlatMap;
+use std_::str::{CharIndices, Chars};
 
 #[cfg(feature = "alloc")]
-use alloc::string::String;
+use alloc::{
+    borrow::Cow,
+    string::String,
+    vec::Vec,
+};
 
+#[cfg(feature = "std")]
+use std_::collections::HashMap;
+
+#[cfg(feature = "alloc")]
+mod char_counted;
 mod iterators;
 
-pub use self::iterators::{CharIndicesFrom, KeyStr, RSplitWhile, SplitWhile};
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+pub use self::char_counted::CharCounted;
+pub use self::iterators::{
+    CharIndicesFrom, CharIndicesRev, KeyStr, RSplitWhile, SplitWhile, WrapWords,
+};
 
 /// Extension trait for strings (any type that borrows as `str`).
 pub trait StringExt: Borrow<str> {
@@ -244,6 +259,43 @@ pub trait StringExt: Borrow<str> {
             s: this,
         }
     }
+    /// Returns an iterator over the lines of this string wrapped to `width` chars,
+    /// breaking lines on ASCII whitespace.
+    ///
+    /// Whitespace inside of a line is preserved,
+    /// while the whitespace at the points where lines are broken is removed.
+    ///
+    /// Words longer than `width` chars are split across multiple lines.
+    ///
+    /// A `width` of `0` is treated like `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!(
+    ///     "the quick brown fox jumps over the lazy dog".wrap_words(10).collect::<Vec<_>>(),
+    ///     vec!["the quick", "brown fox", "jumps over", "the lazy", "dog"],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "  hello    world  ".wrap_words(8).collect::<Vec<_>>(),
+    ///     vec!["hello", "world"],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "a supercalifragilistic word".wrap_words(8).collect::<Vec<_>>(),
+    ///     vec!["a", "supercal", "ifragili", "stic", "word"],
+    /// );
+    ///
+    /// ```
+    fn wrap_words<'a>(&'a self, width: usize) -> WrapWords<'a> {
+        WrapWords {
+            s: self.borrow(),
+            width: cmp::max(width, 1),
+        }
+    }
     /// The byte index of the `nth` character
     ///
     /// If there is no `nth` character, this returns `None`.
@@ -323,6 +375,54 @@ pub trait StringExt: Borrow<str> {
         self.borrow().chars().nth(nth)
     }
 
+    /// Returns the amount of chars in the string.
+    ///
+    /// This operation takes `O(n)` time, where `n` is `self.len()`,
+    /// use [`char_counted`](#method.char_counted) to cache the char count.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!("".count_chars(), 0);
+    /// assert_eq!("foo".count_chars(), 3);
+    /// assert_eq!("débuter".count_chars(), 7);
+    /// assert_eq!("速度惊人".count_chars(), 4);
+    /// ```
+    fn count_chars(&self) -> usize {
+        self.borrow().chars().count()
+    }
+
+    /// Returns a [`CharCounted`], which caches the char count of the string,
+    /// and the byte offsets of its chars once it's indexed by char.
+    ///
+    /// This is useful when the string is indexed by char position many times,
+    /// since the `StringExt` methods that do so take `O(n)` time for every call.
+    /// Look at the [`CharCounted`] docs for the time and memory costs.
+    ///
+    /// [`CharCounted`]: ./strings/struct.CharCounted.html
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let mut word = "débuter".char_counted();
+    ///
+    /// assert_eq!(word.char_count(), 7);
+    ///
+    /// let chars = (0..word.char_count())
+    ///     .rev()
+    ///     .filter_map(|i| word.nth_char(i))
+    ///     .collect::<String>();
+    ///
+    /// assert_eq!(chars, "retubéd");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn char_counted<'a>(&'a self) -> CharCounted<'a> {
+        CharCounted::new(self.borrow())
+    }
+
     /// Returns a string containing the first `n` chars.
     ///
     /// if `n` is greater than the amount of chars, this returns the entire string.
@@ -344,6 +444,97 @@ pub trait StringExt: Borrow<str> {
         let this = self.borrow();
         &this[..this.nth_char_index(n)]
     }
+
+    /// Truncates the string to its first `n` chars.
+    ///
+    /// if `n` is greater than the amount of chars, this returns the entire string.
+    ///
+    /// This is equivalent to [`first_chars`](#method.first_chars).
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let word = "ñu三";
+    ///
+    /// assert_eq!(word.truncate_chars(0), "");
+    /// assert_eq!(word.truncate_chars(2), "ñu");
+    /// assert_eq!(word.truncate_chars(3), "ñu三");
+    /// assert_eq!(word.truncate_chars(10), "ñu三");
+    /// ```
+    #[inline]
+    fn truncate_chars(&self, n: usize) -> &str {
+        self.first_chars(n)
+    }
+
+    /// Returns the length in bytes of the first `n` chars.
+    ///
+    /// if `n` is greater than the amount of chars, this returns `self.len()`.
+    ///
+    /// This is useful for pre-allocating buffers that store a prefix of the string.
+    ///
+    /// This operation takes `O(n)` time, where `n` is `self.len()`.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let word = "ñu三";
+    ///
+    /// assert_eq!(word.byte_len_of_chars(0), 0);
+    /// assert_eq!(word.byte_len_of_chars(1), 2);
+    /// assert_eq!(word.byte_len_of_chars(2), 3);
+    /// assert_eq!(word.byte_len_of_chars(3), 6);
+    /// assert_eq!(word.byte_len_of_chars(4), 6);
+    ///
+    /// let mut buffer = String::with_capacity(word.byte_len_of_chars(2));
+    /// buffer.push_str(word.first_chars(2));
+    /// assert_eq!(buffer, "ñu");
+    /// ```
+    fn byte_len_of_chars(&self, n: usize) -> usize {
+        self.borrow().nth_char_index(n)
+    }
+
+    /// Returns the first `n` chars of the string followed by `ellipsis`
+    /// if the string is longer than `n` chars, otherwise returns the entire string.
+    ///
+    /// This only allocates if the string is truncated.
+    /// Use [`truncate_chars`](#method.truncate_chars) 
+    /// to truncate the string without appending anything.
+    ///
+    /// The chars in `ellipsis` don't count towards `n`.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// use std::borrow::Cow;
+    ///
+    /// let word = "сине";
+    ///
+    /// assert_eq!(word.truncate_chars_with(2, "..."), "си...");
+    /// assert_eq!(word.truncate_chars_with(3, "…"), "син…");
+    /// assert_eq!(word.truncate_chars_with(0, "..."), "...");
+    ///
+    /// // The string is only copied if it was truncated
+    /// assert_eq!(word.truncate_chars_with(4, "..."), Cow::Borrowed("сине"));
+    /// assert_eq!(word.truncate_chars_with(10, "..."), Cow::Borrowed("сине"));
+    /// assert_eq!("".truncate_chars_with(0, "..."), Cow::Borrowed(""));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn truncate_chars_with<'a>(&'a self, n: usize, ellipsis: &str) -> Cow<'a, str> {
+        let this = self.borrow();
+        let end = this.nth_char_index(n);
+        if end == this.len() {
+            Cow::Borrowed(this)
+        } else {
+            let mut out = String::with_capacity(end + ellipsis.len());
+            out.push_str(&this[..end]);
+            out.push_str(ellipsis);
+            Cow::Owned(out)
+        }
+    }
     /// Returns a string containing the last `n` chars
     ///
     /// if `n` is greater than the amount of chars, this returns the entire string.
@@ -399,216 +590,1162 @@ pub trait StringExt: Borrow<str> {
         &this[this.nth_char_index(n)..]
     }
 
-    /// Returns the length of the string in utf16
+    /// Splits the string so that the first half contains the first `n` chars.
     ///
-    /// # Warning
+    /// If `n` is greater than the amount of chars, this returns `(self, "")`.
     ///
-    /// This is calculated every time the function is called.
+    /// This is like `str::split_at`, but taking a char position instead of a byte index.
+    ///
+    /// This operation takes `O(n)` time, where `n` is `self.len()`.
     ///
     /// # Example
+    ///
     /// ```
     /// use core_extensions::StringExt;
     ///
-    /// assert_eq!("foo".calc_len_utf16(), 3);
-    /// assert_eq!("υιός".calc_len_utf16(), 4);
-    /// assert_eq!("👪".calc_len_utf16(), 2);
+    /// let word = "υιός";
     ///
+    /// assert_eq!(word.split_at_char(0), ("", "υιός"));
+    /// assert_eq!(word.split_at_char(1), ("υ", "ιός"));
+    /// assert_eq!(word.split_at_char(3), ("υιό", "ς"));
+    /// assert_eq!(word.split_at_char(4), ("υιός", ""));
+    /// assert_eq!(word.split_at_char(5), ("υιός", ""));
     /// ```
-    fn calc_len_utf16(&self) -> usize {
-        self.borrow()
-            .chars()
-            .fold(0, |accum, c| accum + c.len_utf16())
+    fn split_at_char(&self, n: usize) -> (&str, &str) {
+        let this = self.borrow();
+        this.split_at(this.nth_char_index(n))
     }
-    /// Returns the character at the `at_byte` index inside of the string,
-    /// returning `None` if the index is outside the string.
+
+    /// Splits the string so that the second half contains the last `n` chars.
     ///
-    /// If the index is between char boundaries,
-    /// this returns the char at the previous char boundary.
+    /// If `n` is greater than the amount of chars, this returns `("", self)`.
     ///
-    /// If `self.len() <= index`, this returns none.
+    /// This operation takes `O(n)` time, where `n` is `self.len()`.
     ///
     /// # Example
+    ///
     /// ```
     /// use core_extensions::StringExt;
     ///
-    /// let word = "foo 効 门";
-    ///
-    /// assert_eq!(word.get_char_at(0), Some('f'));
-    /// assert_eq!(word.get_char_at(1), Some('o'));
-    /// assert_eq!(word.get_char_at(2), Some('o'));
-    /// assert_eq!(word.get_char_at(3), Some(' '));
-    /// assert_eq!(word.get_char_at(4), Some('効'));
-    /// assert_eq!(word.get_char_at(5), Some('効'));
-    /// assert_eq!(word.get_char_at(6), Some('効'));
-    /// assert_eq!(word.get_char_at(7), Some(' '));
-    /// assert_eq!(word.get_char_at(8), Some('门'));
-    /// assert_eq!(word.get_char_at(9), Some('门'));
-    /// assert_eq!(word.get_char_at(10), Some('门'));
-    /// assert_eq!(word.get_char_at(11), None);
+    /// let word = "υιός";
     ///
+    /// assert_eq!(word.rsplit_at_char(0), ("υιός", ""));
+    /// assert_eq!(word.rsplit_at_char(1), ("υιό", "ς"));
+    /// assert_eq!(word.rsplit_at_char(3), ("υ", "ιός"));
+    /// assert_eq!(word.rsplit_at_char(4), ("", "υιός"));
+    /// assert_eq!(word.rsplit_at_char(5), ("", "υιός"));
     /// ```
-    ///
-    fn get_char_at(&self, at_byte: usize) -> Option<char> {
+    fn rsplit_at_char(&self, n: usize) -> (&str, &str) {
         let this = self.borrow();
-        if at_byte >= this.len() {
-            return None;
-        }
-        let start = this.left_char_boundary(at_byte);
-        this[start..].chars().nth(0)
+        let end_len = this.last_chars(n).len();
+        this.split_at(this.len() - end_len)
     }
 
-    /// Returns an iterator over (index,char) pairs up to 
-    /// (but not including) the char at the `to` byte.
-    ///
-    /// IF the index is between char boundaries,
-    /// it doesn't include the char that index is inside of.
+    /// Returns the length in bytes of the longest common prefix of `self` and `other`.
     ///
-    /// if `index > self.len()`, returns an iterator over the entire string.
+    /// The returned length is always at a char boundary of both strings,
+    /// if the strings diverge in the middle of a char the prefix ends before that char.
     ///
     /// # Example
+    ///
     /// ```
     /// use core_extensions::StringExt;
     ///
-    /// let word = "foo 効 ";
+    /// assert_eq!("server.port".common_prefix_len("server.host"), 7);
+    /// assert_eq!("foo".common_prefix_len("foobar"), 3);
+    /// assert_eq!("foo".common_prefix_len("bar"), 0);
     ///
-    /// assert_eq!(word.char_indices_to(0).collect::<Vec<_>>(), vec![]);
-    /// assert_eq!(word.char_indices_to(1).collect::<Vec<_>>(), vec![(0, 'f')]);
-    /// 
-    /// let expected_a = vec![(0, 'f'), (1, 'o'), (2, 'o'), (3, ' ')];
-    /// assert_eq!(word.char_indices_to(4).collect::<Vec<_>>(), expected_a);
-    /// assert_eq!(word.char_indices_to(5).collect::<Vec<_>>(), expected_a);
-    /// assert_eq!(word.char_indices_to(6).collect::<Vec<_>>(), expected_a);
-    /// 
-    /// let expected_b = vec![(0, 'f'), (1, 'o'), (2, 'o'), (3, ' '), (4, '効')];
-    /// assert_eq!(word.char_indices_to(7).collect::<Vec<_>>(), expected_b);
-    ///     
-    /// let expected_c = vec![(0, 'f'), (1, 'o'), (2, 'o'), (3, ' '), (4, '効'), (7, ' ')];
-    /// assert_eq!(word.char_indices_to(8).collect::<Vec<_>>(), expected_c);
-    /// assert_eq!(word.char_indices_to(100).collect::<Vec<_>>(), expected_c);
-    /// ```
+    /// // 'é' and 'è' share their first byte
+    /// assert_eq!("café".common_prefix_len("cafè"), 3);
     ///
-    fn char_indices_to(&self, to: usize) -> CharIndices {
-        let this = self.borrow();
-        let to = this.left_char_boundary(to);
-        this[..to].char_indices()
+    /// ```
+    fn common_prefix_len(&self, other: &str) -> usize {
+        self.borrow()
+            .chars()
+            .zip(other.chars())
+            .take_while(|(l, r)| l == r)
+            .map(|(c, _)| c.len_utf8())
+            .sum()
     }
 
-    /// Returns an iterator over (index, char) pairs, starting from the `from` byte.
+    /// Returns the length in bytes of the longest common suffix of `self` and `other`.
     ///
-    /// If the index is between char boundaries,
-    /// this starts from the char at the previous char boundary.
-    ///
-    /// if `index > self.len()`, returns an empty iterator.
+    /// The returned length is always at a char boundary of both strings
+    /// (counting from the end),
+    /// if the strings diverge in the middle of a char the suffix starts after that char.
     ///
     /// # Example
+    ///
     /// ```
     /// use core_extensions::StringExt;
     ///
-    /// let word = "foo 効 ";
+    /// assert_eq!("client.timeout".common_suffix_len("server.timeout"), 8);
+    /// assert_eq!("bar".common_suffix_len("foobar"), 3);
+    /// assert_eq!("foo".common_suffix_len("bar"), 0);
     ///
-    /// let expected_a = vec![(0, 'f'), (1, 'o'), (2, 'o'), (3, ' '), (4, '効'), (7, ' ')];
-    /// assert_eq!(word.char_indices_from(0).collect::<Vec<_>>(), expected_a);
+    /// // 'ő' and 'ű' share their first byte
+    /// assert_eq!("ő!".common_suffix_len("ű!"), 1);
+    /// // 'Ā' and 'ā' share their last byte
+    /// assert_eq!("Āb".common_suffix_len("āb"), 1);
     ///
-    /// let expected_b = vec![(1, 'o'), (2, 'o'), (3, ' '), (4, '効'), (7, ' ')];
-    /// assert_eq!(word.char_indices_from(1).collect::<Vec<_>>(), expected_b);
+    /// ```
+    fn common_suffix_len(&self, other: &str) -> usize {
+        self.borrow()
+            .chars()
+            .rev()
+            .zip(other.chars().rev())
+            .take_while(|(l, r)| l == r)
+            .map(|(c, _)| c.len_utf8())
+            .sum()
+    }
+
+    /// Returns the longest common prefix of `self` and `other`,
+    /// as a slice of `self`.
     ///
-    /// let expected_c = vec![(3, ' '), (4, '効'), (7, ' ')];
-    /// assert_eq!(word.char_indices_from(3).collect::<Vec<_>>(), expected_c);
+    /// The prefix only contains chars that are fully shared by both strings.
     ///
-    /// let expected_c = vec![(4, '効'), (7, ' ')];
-    /// assert_eq!(word.char_indices_from(4).collect::<Vec<_>>(), expected_c);
-    /// assert_eq!(word.char_indices_from(5).collect::<Vec<_>>(), expected_c);
-    /// assert_eq!(word.char_indices_from(6).collect::<Vec<_>>(), expected_c);
-    /// 
-    /// assert_eq!(word.char_indices_from(7).collect::<Vec<_>>(), vec![(7, ' ')]);
+    /// # Example
     ///
-    /// assert_eq!(word.char_indices_from(8).collect::<Vec<_>>(), vec![]);
+    /// ```
+    /// use core_extensions::StringExt;
     ///
-    /// assert_eq!(word.char_indices_from(9).collect::<Vec<_>>(), vec![]);
+    /// assert_eq!("server.port".common_prefix("server.host"), "server.");
+    /// assert_eq!("foo".common_prefix("bar"), "");
+    /// assert_eq!("café".common_prefix("cafè"), "caf");
     ///
     /// ```
-    fn char_indices_from(&self, from: usize) -> CharIndicesFrom {
+    fn common_prefix<'a>(&'a self, other: &str) -> &'a str {
         let this = self.borrow();
-        let from = this.left_char_boundary(from);
-        CharIndicesFrom {
-            offset: from,
-            iter: this[from..].char_indices(),
-        }
+        &this[..this.common_prefix_len(other)]
     }
 
-    /// Pads the string on the left with `how_much` additional spaces.
+    /// Returns the longest common suffix of `self` and `other`,
+    /// as a slice of `self`.
+    ///
+    /// The suffix only contains chars that are fully shared by both strings.
     ///
     /// # Example
     ///
     /// ```
     /// use core_extensions::StringExt;
     ///
-    /// assert_eq!(
-    ///     "what\n  the\n    hall".left_pad(4),
-    ///     "    what\n      the\n        hall"
-    /// );
-    /// ```
+    /// assert_eq!("client.timeout".common_suffix("server.timeout"), ".timeout");
+    /// assert_eq!("foo".common_suffix("bar"), "");
+    /// assert_eq!("Āb".common_suffix("āb"), "b");
     ///
-    #[cfg(feature = "alloc")]
-    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
-    fn left_pad(&self, how_much: usize) -> String {
-        use alloc::string::ToString;
-        self.left_padder(how_much).to_string()
+    /// ```
+    fn common_suffix<'a>(&'a self, other: &str) -> &'a str {
+        let this = self.borrow();
+        &this[this.len() - this.common_suffix_len(other)..]
     }
-    /// Returns a value that pads the string on the left with `how_much` additional
-    /// spaces in its `Display` impl.
+
+    /// Splits the string at the last char boundary where 
+    /// the display width of the first half is at most `width`.
     ///
-    /// Use this to avoid allocating an extra string.
+    /// The display width of each char is approximated as follows:
     ///
-    /// # Example
+    /// - Control chars have a width of 0.
     ///
-    #[cfg_attr(not(feature = "alloc"), doc = " ```ignore")]
-    #[cfg_attr(feature = "alloc", doc = " ```rust")]
-    /// use core_extensions::StringExt;
+    /// - East Asian wide and fullwidth chars
+    ///   (CJK ideographs, Hangul syllables, Hiragana, Katakana, fullwidth forms, 
+    ///   and common emoji) have a width of 2.
     ///
-    /// assert_eq!(
-    ///     "what\n  the\n    hall".left_pad(4).to_string(),
-    ///     "    what\n      the\n        hall"
-    /// );
-    /// ```
+    /// - All other chars have a width of 1.
     ///
-    fn left_padder<'a>(&'a self, how_much: usize) -> LeftPadder<'a> {
-        LeftPadder::new(self.borrow(), how_much)
-    }
-    /// The indentation of the first line.
+    /// This is a heuristic based on char ranges,
+    /// computing the exact display width requires Unicode tables that this crate doesn't have.
     ///
-    /// This considers lines that only contains whitespace to have as 
-    /// much indentation as they're long.
+    /// A wide char that would make the first half wider than `width` 
+    /// is put in the second half.
     ///
     /// # Example
     ///
     /// ```
     /// use core_extensions::StringExt;
     ///
-    /// assert_eq!("".line_indentation(), 0);
-    /// assert_eq!("    ".line_indentation(), 4);
-    /// assert_eq!("    \n      word".line_indentation(), 4);
-    /// assert_eq!("    \nword".line_indentation(), 4);
+    /// assert_eq!("foobar".split_at_display_width(3), ("foo", "bar"));
+    /// assert_eq!("foobar".split_at_display_width(10), ("foobar", ""));
     ///
-    /// ```
+    /// // CJK chars are two columns wide
+    /// assert_eq!("速度惊人".split_at_display_width(4), ("速度", "惊人"));
+    /// assert_eq!("速度惊人".split_at_display_width(5), ("速度", "惊人"));
+    /// assert_eq!("a速度".split_at_display_width(2), ("a", "速度"));
+    /// assert_eq!("a速度".split_at_display_width(3), ("a速", "度"));
     ///
-    #[cfg(any(core_str_methods, feature = "alloc"))]
-    fn line_indentation(&self) -> usize {
-        let this = self.borrow().lines().next().unwrap_or("");
-        this.len() - this.trim_start().len()
+    /// assert_eq!("速度".split_at_display_width(0), ("", "速度"));
+    /// assert_eq!("速度".split_at_display_width(1), ("", "速度"));
+    ///
+    /// ```
+    fn split_at_display_width(&self, width: usize) -> (&str, &str) {
+        let this = self.borrow();
+        let mut remaining = width;
+        for (i, c) in this.char_indices() {
+            match remaining.checked_sub(char_display_width(c)) {
+                Some(x) => remaining = x,
+                None => return this.split_at(i),
+            }
+        }
+        (this, &this[this.len()..])
     }
 
-    /// The minimum indentation of the string, ignoring lines that only contain whitespace.
+    /// Splits the string on the first occurrence of `sep`,
+    /// returning both halves with leading and trailing whitespace removed.
+    ///
+    /// Returns `None` if the string doesn't contain `sep`.
     ///
     /// # Example
     ///
     /// ```
     /// use core_extensions::StringExt;
     ///
-    /// assert_eq!("".min_indentation(), 0);
-    /// assert_eq!("    ".min_indentation(), 0);
-    /// assert_eq!("    \nf".min_indentation(), 0);
-    /// assert_eq!("    \n      word".min_indentation(), 6);
+    /// assert_eq!("key = value".parse_key_value('='), Some(("key", "value")));
+    /// assert_eq!("  name:Bob: Jr ".parse_key_value(':'), Some(("name", "Bob: Jr")));
+    /// assert_eq!("empty =".parse_key_value('='), Some(("empty", "")));
+    /// assert_eq!("= value".parse_key_value('='), Some(("", "value")));
+    ///
+    /// assert_eq!("no separator".parse_key_value('='), None);
+    /// assert_eq!("".parse_key_value('='), None);
+    ///
+    /// ```
+    fn parse_key_value(&self, sep: char) -> Option<(&str, &str)> {
+        let this = self.borrow();
+        let index = this.find(sep)?;
+        let key = &this[..index];
+        let value = &this[index + sep.len_utf8()..];
+        Some((key.trim(), value.trim()))
+    }
+
+    /// Escapes newlines, tabs, backslashes, and double quotes with a backslash.
+    ///
+    /// [`unescape_basic`](#method.unescape_basic) does the inverse of this.
+    ///
+    /// - newlines are escaped as `\n`
+    /// - tabs are escaped as `\t`
+    /// - backslashes are escaped as `\\`
+    /// - double quotes are escaped as `\"`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!("foo".escape_basic(), "foo");
+    /// assert_eq!("say \"hi\"\n\tok".escape_basic(), r#"say \"hi\"\n\tok"#);
+    /// assert_eq!(r"C:\dir".escape_basic(), r"C:\\dir");
+    ///
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn escape_basic(&self) -> String {
+        let this = self.borrow();
+        let mut out = String::with_capacity(this.len());
+        for c in this.chars() {
+            match c {
+                '\n' => out.push_str("\\n"),
+                '\t' => out.push_str("\\t"),
+                '\\' => out.push_str("\\\\"),
+                '"' => out.push_str("\\\""),
+                _ => out.push(c),
+            }
+        }
+        out
+    }
+
+    /// Unescapes the escape sequences produced by 
+    /// [`escape_basic`](#method.escape_basic).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the string contains a backslash that isn't
+    /// followed by one of `n`, `t`, `\`, or `"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    /// use core_extensions::strings::UnescapeError;
+    ///
+    /// assert_eq!(r#"say \"hi\"\n\tok"#.unescape_basic(), Ok("say \"hi\"\n\tok".to_string()));
+    ///
+    /// let text = "\"quoted\"\n\\path\\";
+    /// assert_eq!(text.escape_basic().unescape_basic().unwrap(), text);
+    ///
+    /// assert_eq!(r"foo\".unescape_basic(), Err(UnescapeError::DanglingBackslash{index: 3}));
+    /// assert_eq!(
+    ///     r"a\qb".unescape_basic(),
+    ///     Err(UnescapeError::InvalidEscape{index: 1, found: 'q'}),
+    /// );
+    ///
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn unescape_basic(&self) -> Result<String, UnescapeError> {
+        let this = self.borrow();
+        let mut out = String::with_capacity(this.len());
+        let mut iter = this.char_indices();
+        while let Some((index, c)) = iter.next() {
+            if c != '\\' {
+                out.push(c);
+                continue;
+            }
+            match iter.next() {
+                Some((_, 'n')) => out.push('\n'),
+                Some((_, 't')) => out.push('\t'),
+                Some((_, '\\')) => out.push('\\'),
+                Some((_, '"')) => out.push('"'),
+                Some((_, found)) => return Err(UnescapeError::InvalidEscape { index, found }),
+                None => return Err(UnescapeError::DanglingBackslash { index }),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Returns a copy of this string where the first character after every boundary,
+    /// and the first character of the string, are uppercased.
+    ///
+    /// A boundary is any character for which `is_boundary` returns true,
+    /// boundary characters are kept in the returned string,
+    /// and the characters that aren't after a boundary are not modified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!("hello_world".title_case_by(|c| c == '_'), "Hello_World");
+    /// assert_eq!("hello world".title_case_by(char::is_whitespace), "Hello World");
+    /// assert_eq!("foo-bar.baz".title_case_by(|c| c == '-' || c == '.'), "Foo-Bar.Baz");
+    ///
+    /// // Replacing the boundaries after title casing, to convert snake_case to Title Case
+    /// assert_eq!(
+    ///     "snake_case_string".title_case_by(|c| c == '_').replace('_', " "),
+    ///     "Snake Case String",
+    /// );
+    ///
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn title_case_by<F>(&self, mut is_boundary: F) -> String
+    where
+        F: FnMut(char) -> bool,
+    {
+        let this = self.borrow();
+        let mut out = String::with_capacity(this.len());
+        let mut after_boundary = true;
+        for c in this.chars() {
+            if after_boundary {
+                out.extend(c.to_uppercase());
+            } else {
+                out.push(c);
+            }
+            after_boundary = is_boundary(c);
+        }
+        out
+    }
+
+    /// Returns the length of the string in utf16
+    ///
+    /// # Warning
+    ///
+    /// This is calculated every time the function is called.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!("foo".calc_len_utf16(), 3);
+    /// assert_eq!("υιός".calc_len_utf16(), 4);
+    /// assert_eq!("👪".calc_len_utf16(), 2);
+    ///
+    /// ```
+    fn calc_len_utf16(&self) -> usize {
+        self.borrow()
+            .chars()
+            .fold(0, |accum, c| accum + c.len_utf16())
+    }
+    /// Returns the character at the `at_byte` index inside of the string,
+    /// returning `None` if the index is outside the string.
+    ///
+    /// If the index is between char boundaries,
+    /// this returns the char at the previous char boundary.
+    ///
+    /// If `self.len() <= index`, this returns none.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let word = "foo 効 门";
+    ///
+    /// assert_eq!(word.get_char_at(0), Some('f'));
+    /// assert_eq!(word.get_char_at(1), Some('o'));
+    /// assert_eq!(word.get_char_at(2), Some('o'));
+    /// assert_eq!(word.get_char_at(3), Some(' '));
+    /// assert_eq!(word.get_char_at(4), Some('効'));
+    /// assert_eq!(word.get_char_at(5), Some('効'));
+    /// assert_eq!(word.get_char_at(6), Some('効'));
+    /// assert_eq!(word.get_char_at(7), Some(' '));
+    /// assert_eq!(word.get_char_at(8), Some('门'));
+    /// assert_eq!(word.get_char_at(9), Some('门'));
+    /// assert_eq!(word.get_char_at(10), Some('门'));
+    /// assert_eq!(word.get_char_at(11), None);
+    ///
+    /// ```
+    ///
+    fn get_char_at(&self, at_byte: usize) -> Option<char> {
+        let this = self.borrow();
+        if at_byte >= this.len() {
+            return None;
+        }
+        let start = this.left_char_boundary(at_byte);
+        this[start..].chars().nth(0)
+    }
+
+    /// Returns the character at the `at_byte` index inside of the string,
+    /// returning `default` if the index is outside the string.
+    ///
+    /// If the index is between char boundaries,
+    /// this returns the char at the previous char boundary.
+    ///
+    /// If `self.len() <= index`, this returns `default`.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let word = "foo 効 门";
+    ///
+    /// assert_eq!(word.char_at_or(0, '_'), 'f');
+    /// assert_eq!(word.char_at_or(3, '_'), ' ');
+    /// assert_eq!(word.char_at_or(4, '_'), '効');
+    /// assert_eq!(word.char_at_or(5, '_'), '効');
+    /// assert_eq!(word.char_at_or(6, '_'), '効');
+    /// assert_eq!(word.char_at_or(8, '_'), '门');
+    /// assert_eq!(word.char_at_or(10, '_'), '门');
+    /// assert_eq!(word.char_at_or(11, '_'), '_');
+    /// assert_eq!(word.char_at_or(100, '_'), '_');
+    ///
+    /// ```
+    ///
+    fn char_at_or(&self, at_byte: usize, default: char) -> char {
+        self.get_char_at(at_byte).unwrap_or(default)
+    }
+
+    /// Returns the line and column of the `byte` index inside of the string,
+    /// for use in diagnostics.
+    ///
+    /// The line is 1-based, counting `'\n'` line breaks,
+    /// the column is 0-based, counted in chars.
+    ///
+    /// If the index is between char boundaries,
+    /// this returns the column of the char that index is inside of.
+    ///
+    /// If `byte > self.len()`, this returns the line and column of the end of the string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let text = "fn foo() {\n    效果 = 3;\n}";
+    ///
+    /// assert_eq!(text.line_col_at(0), (1, 0));
+    /// assert_eq!(text.line_col_at(3), (1, 3));
+    /// assert_eq!(text.line_col_at(10), (1, 10));
+    /// assert_eq!(text.line_col_at(11), (2, 0));
+    /// assert_eq!(text.line_col_at(15), (2, 4));
+    /// assert_eq!(text.line_col_at(18), (2, 5));
+    /// assert_eq!(text.line_col_at(22), (2, 7));
+    /// assert_eq!(text.line_col_at(27), (3, 0));
+    /// assert_eq!(text.line_col_at(1000), (3, 1));
+    ///
+    /// ```
+    ///
+    fn line_col_at(&self, byte: usize) -> (usize, usize) {
+        let this = self.borrow();
+        let prefix = &this[..this.left_char_boundary(byte)];
+        let line = 1 + prefix.bytes().filter(|&b| b == b'\n').count();
+        let line_start = prefix.rfind('\n').map_or(0, |i| i + 1);
+        (line, prefix[line_start..].chars().count())
+    }
+
+    /// Returns an iterator over (index,char) pairs up to 
+    /// (but not including) the char at the `to` byte.
+    ///
+    /// IF the index is between char boundaries,
+    /// it doesn't include the char that index is inside of.
+    ///
+    /// if `index > self.len()`, returns an iterator over the entire string.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let word = "foo 効 ";
+    ///
+    /// assert_eq!(word.char_indices_to(0).collect::<Vec<_>>(), vec![]);
+    /// assert_eq!(word.char_indices_to(1).collect::<Vec<_>>(), vec![(0, 'f')]);
+    /// 
+    /// let expected_a = vec![(0, 'f'), (1, 'o'), (2, 'o'), (3, ' ')];
+    /// assert_eq!(word.char_indices_to(4).collect::<Vec<_>>(), expected_a);
+    /// assert_eq!(word.char_indices_to(5).collect::<Vec<_>>(), expected_a);
+    /// assert_eq!(word.char_indices_to(6).collect::<Vec<_>>(), expected_a);
+    /// 
+    /// let expected_b = vec![(0, 'f'), (1, 'o'), (2, 'o'), (3, ' '), (4, '効')];
+    /// assert_eq!(word.char_indices_to(7).collect::<Vec<_>>(), expected_b);
+    ///     
+    /// let expected_c = vec![(0, 'f'), (1, 'o'), (2, 'o'), (3, ' '), (4, '効'), (7, ' ')];
+    /// assert_eq!(word.char_indices_to(8).collect::<Vec<_>>(), expected_c);
+    /// assert_eq!(word.char_indices_to(100).collect::<Vec<_>>(), expected_c);
+    /// ```
+    ///
+    fn char_indices_to(&self, to: usize) -> CharIndices {
+        let this = self.borrow();
+        let to = this.left_char_boundary(to);
+        this[..to].char_indices()
+    }
+
+    /// Returns an iterator over (index, char) pairs, starting from the `from` byte.
+    ///
+    /// If the index is between char boundaries,
+    /// this starts from the char at the previous char boundary.
+    ///
+    /// if `index > self.len()`, returns an empty iterator.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let word = "foo 効 ";
+    ///
+    /// let expected_a = vec![(0, 'f'), (1, 'o'), (2, 'o'), (3, ' '), (4, '効'), (7, ' ')];
+    /// assert_eq!(word.char_indices_from(0).collect::<Vec<_>>(), expected_a);
+    ///
+    /// let expected_b = vec![(1, 'o'), (2, 'o'), (3, ' '), (4, '効'), (7, ' ')];
+    /// assert_eq!(word.char_indices_from(1).collect::<Vec<_>>(), expected_b);
+    ///
+    /// let expected_c = vec![(3, ' '), (4, '効'), (7, ' ')];
+    /// assert_eq!(word.char_indices_from(3).collect::<Vec<_>>(), expected_c);
+    ///
+    /// let expected_c = vec![(4, '効'), (7, ' ')];
+    /// assert_eq!(word.char_indices_from(4).collect::<Vec<_>>(), expected_c);
+    /// assert_eq!(word.char_indices_from(5).collect::<Vec<_>>(), expected_c);
+    /// assert_eq!(word.char_indices_from(6).collect::<Vec<_>>(), expected_c);
+    /// 
+    /// assert_eq!(word.char_indices_from(7).collect::<Vec<_>>(), vec![(7, ' ')]);
+    ///
+    /// assert_eq!(word.char_indices_from(8).collect::<Vec<_>>(), vec![]);
+    ///
+    /// assert_eq!(word.char_indices_from(9).collect::<Vec<_>>(), vec![]);
+    ///
+    /// ```
+    fn char_indices_from(&self, from: usize) -> CharIndicesFrom {
+        let this = self.borrow();
+        let from = this.left_char_boundary(from);
+        CharIndicesFrom {
+            offset: from,
+            iter: this[from..].char_indices(),
+        }
+    }
+
+    /// Returns an iterator over the chars of the string, and their byte indices,
+    /// going from the end of the string to the start.
+    ///
+    /// The byte indices are the ones that 
+    /// [`str::char_indices`](https://doc.rust-lang.org/std/primitive.str.html#method.char_indices)
+    /// returns, the position of the start of each char.
+    ///
+    /// # Example
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let word = "foo 効 ";
+    ///
+    /// let expected = vec![(7, ' '), (4, '効'), (3, ' '), (2, 'o'), (1, 'o'), (0, 'f')];
+    /// assert_eq!(word.char_indices_rev().collect::<Vec<_>>(), expected);
+    ///
+    /// let mut iter = word.char_indices_rev();
+    /// assert_eq!(iter.next(), Some((7, ' ')));
+    /// assert_eq!(iter.next(), Some((4, '効')));
+    /// assert_eq!(iter.as_str(), "foo ");
+    /// assert_eq!(iter.next_back(), Some((0, 'f')));
+    /// assert_eq!(iter.as_str(), "oo ");
+    ///
+    /// ```
+    fn char_indices_rev<'a>(&'a self) -> CharIndicesRev<'a> {
+        CharIndicesRev {
+            iter: self.borrow().char_indices(),
+        }
+    }
+
+    /// Returns whether `pred` returns true for all the chars in the string.
+    ///
+    /// This returns true for the empty string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// fn is_ident_char(c: char) -> bool {
+    ///     c.is_alphanumeric() || c == '_'
+    /// }
+    ///
+    /// assert!("foo_bar".contains_only(is_ident_char));
+    /// assert!("_número2".contains_only(is_ident_char));
+    /// assert!(!"foo-bar".contains_only(is_ident_char));
+    /// assert!(!"foo bar".contains_only(is_ident_char));
+    ///
+    /// assert!("".contains_only(|_| false));
+    ///
+    /// ```
+    ///
+    fn contains_only<F>(&self, pred: F) -> bool
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.borrow().chars().all(pred)
+    }
+
+    /// Returns whether all the chars in the string are alphanumeric,
+    /// as determined by `char::is_alphanumeric`.
+    ///
+    /// This returns true for the empty string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert!("foo123".is_all_alphanumeric());
+    /// assert!("ñandú".is_all_alphanumeric());
+    /// assert!("".is_all_alphanumeric());
+    /// assert!(!"foo_123".is_all_alphanumeric());
+    /// assert!(!"foo 123".is_all_alphanumeric());
+    ///
+    /// ```
+    ///
+    fn is_all_alphanumeric(&self) -> bool {
+        self.contains_only(char::is_alphanumeric)
+    }
+
+    /// Returns whether all the chars in the string are whitespace,
+    /// as determined by `char::is_whitespace`.
+    ///
+    /// This returns true for the empty string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert!("  \t\n ".is_all_whitespace());
+    /// assert!("".is_all_whitespace());
+    /// assert!(!"  a  ".is_all_whitespace());
+    ///
+    /// ```
+    ///
+    fn is_all_whitespace(&self) -> bool {
+        self.contains_only(char::is_whitespace)
+    }
+
+    /// Returns whether the string starts with any of the `prefixes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let path = "./src/lib.rs";
+    ///
+    /// assert!(path.starts_with_any(vec!["/", "./"]));
+    /// assert!(path.starts_with_any(["./src", "./tests"].iter().copied()));
+    /// assert!(!path.starts_with_any(vec!["/", "../"]));
+    /// assert!(!path.starts_with_any(Vec::new()));
+    ///
+    /// ```
+    ///
+    fn starts_with_any<'a, I>(&self, prefixes: I) -> bool
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let this = self.borrow();
+        prefixes.into_iter().any(|prefix| this.starts_with(prefix))
+    }
+
+    /// Returns whether the string ends with any of the `suffixes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let extensions = [".rs", ".toml"];
+    ///
+    /// assert!("lib.rs".ends_with_any(extensions.iter().copied()));
+    /// assert!("Cargo.toml".ends_with_any(extensions.iter().copied()));
+    /// assert!(!"README.md".ends_with_any(extensions.iter().copied()));
+    /// assert!(!"rs".ends_with_any(extensions.iter().copied()));
+    ///
+    /// ```
+    ///
+    fn ends_with_any<'a, I>(&self, suffixes: I) -> bool
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let this = self.borrow();
+        suffixes.into_iter().any(|suffix| this.ends_with(suffix))
+    }
+
+    /// Returns an iterator over the chars that `mapper` returns for each char in the string.
+    ///
+    /// `mapper` can return any `IntoIterator<Item = char>`,
+    /// so it can replace a char with zero, one, or many chars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// use std::iter;
+    ///
+    /// fn rot13(c: char) -> char {
+    ///     match c {
+    ///         'a'..='z' => (((c as u8 - b'a') + 13) % 26 + b'a') as char,
+    ///         'A'..='Z' => (((c as u8 - b'A') + 13) % 26 + b'A') as char,
+    ///         _ => c,
+    ///     }
+    /// }
+    ///
+    /// let rotated = "Hello, 世界!".flat_map_chars(|c| iter::once(rot13(c))).collect::<String>();
+    /// assert_eq!(rotated, "Uryyb, 世界!");
+    ///
+    /// let unrotated = rotated.flat_map_chars(|c| iter::once(rot13(c))).collect::<String>();
+    /// assert_eq!(unrotated, "Hello, 世界!");
+    ///
+    /// let doubled = "ab速".flat_map_chars(|c| vec![c, c]).collect::<String>();
+    /// assert_eq!(doubled, "aabb速速");
+    ///
+    /// let vowelless = "education".flat_map_chars(|c| Some(c).filter(|c| !"aeiou".contains(*c)));
+    /// assert_eq!(vowelless.collect::<String>(), "dctn");
+    ///
+    /// ```
+    ///
+    fn flat_map_chars<'a, F, I>(&'a self, mapper: F) -> FlatMap<Chars<'a>, I, F>
+    where
+        F: FnMut(char) -> I,
+        I: IntoIterator<Item = char>,
+    {
+        self.borrow().chars().flat_map(mapper)
+    }
+
+    /// Constructs a `String` by replacing every char in the string with
+    /// the char that `mapper` returns for it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// fn rot13(c: char) -> char {
+    ///     match c {
+    ///         'a'..='z' => (((c as u8 - b'a') + 13) % 26 + b'a') as char,
+    ///         'A'..='Z' => (((c as u8 - b'A') + 13) % 26 + b'A') as char,
+    ///         _ => c,
+    ///     }
+    /// }
+    ///
+    /// let rotated = "Hello, 世界!".map_chars(rot13);
+    /// assert_eq!(rotated, "Uryyb, 世界!");
+    /// assert_eq!(rotated.map_chars(rot13), "Hello, 世界!");
+    ///
+    /// assert_eq!("ñandú".map_chars(|c| c.to_ascii_uppercase()), "ñANDú");
+    ///
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn map_chars<F>(&self, mapper: F) -> String
+    where
+        F: FnMut(char) -> char,
+    {
+        self.borrow().chars().map(mapper).collect()
+    }
+
+    /// Computes the Levenshtein distance between this string and `other`,
+    /// the minimum amount of single-char insertions, deletions, or substitutions
+    /// required to turn one into the other.
+    ///
+    /// This compares the strings by `char`s, not bytes.
+    ///
+    /// This operation takes `O(n * m)` time,
+    /// where `n` and `m` are the amount of chars in each string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!("kitten".levenshtein_distance("sitting"), 3);
+    /// assert_eq!("flaw".levenshtein_distance("lawn"), 2);
+    /// assert_eq!("".levenshtein_distance("abc"), 3);
+    /// assert_eq!("same".levenshtein_distance("same"), 0);
+    ///
+    /// // Each of these chars is multiple bytes long
+    /// assert_eq!("ñandú".levenshtein_distance("nandu"), 2);
+    /// assert_eq!("日本語".levenshtein_distance("日本"), 1);
+    ///
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn levenshtein_distance(&self, other: &str) -> usize {
+        let other: Vec<char> = other.chars().collect();
+
+        // The distances between the chars of `self` processed so far,
+        // and every prefix of `other`.
+        let mut row: Vec<usize> = (0..=other.len()).collect();
+
+        for (i, c) in self.borrow().chars().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for (j, &o) in other.iter().enumerate() {
+                let substitution = diagonal + (c != o) as usize;
+                diagonal = row[j + 1];
+                row[j + 1] = cmp::min(substitution, cmp::min(row[j], row[j + 1]) + 1);
+            }
+        }
+
+        row[other.len()]
+    }
+
+    /// Computes how similar this string is to `other`, from `0.0` to `1.0`,
+    /// based on their [Levenshtein distance](#method.levenshtein_distance).
+    ///
+    /// This is `1.0` for equal strings (including two empty ones),
+    /// and `0.0` for strings with no chars in common at any position.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!("same".similarity_ratio("same"), 1.0);
+    /// assert_eq!("".similarity_ratio(""), 1.0);
+    /// assert_eq!("abc".similarity_ratio("xyz"), 0.0);
+    /// assert_eq!("ñandú".similarity_ratio("nandu"), 0.6);
+    ///
+    /// let candidates = ["commit", "checkout", "clone"];
+    /// let best = candidates.iter()
+    ///     .max_by(|l, r| "chekout".similarity_ratio(l).partial_cmp(&"chekout".similarity_ratio(r)).unwrap())
+    ///     .unwrap();
+    /// assert_eq!(*best, "checkout");
+    ///
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn similarity_ratio(&self, other: &str) -> f64 {
+        let this = self.borrow();
+        let max_len = cmp::max(this.chars().count(), other.chars().count());
+        if max_len == 0 {
+            return 1.0;
+        }
+        1.0 - this.levenshtein_distance(other) as f64 / max_len as f64
+    }
+
+    /// Counts how many times each char occurs in the string.
+    ///
+    /// The iteration order of the returned map is unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let freqs = "aabbbc".char_frequency();
+    ///
+    /// assert_eq!(freqs.len(), 3);
+    /// assert_eq!(freqs[&'a'], 2);
+    /// assert_eq!(freqs[&'b'], 3);
+    /// assert_eq!(freqs[&'c'], 1);
+    /// assert_eq!(freqs.get(&'d'), None);
+    ///
+    /// assert_eq!("ñoño".char_frequency()[&'ñ'], 2);
+    ///
+    /// ```
+    ///
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+    fn char_frequency(&self) -> HashMap<char, usize> {
+        let mut map = HashMap::new();
+        for c in self.borrow().chars() {
+            *map.entry(c).or_insert(0) += 1;
+        }
+        map
+    }
+
+    /// Returns the char that occurs the most times in the string, and how many times it occurs.
+    ///
+    /// If multiple chars occur the most times, this returns the one that occurs first.
+    ///
+    /// Returns `None` if the string is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!("aabbbc".most_common_char(), Some(('b', 3)));
+    /// assert_eq!("abcabc".most_common_char(), Some(('a', 2)));
+    /// assert_eq!("cbacba".most_common_char(), Some(('c', 2)));
+    /// assert_eq!("".most_common_char(), None);
+    ///
+    /// ```
+    ///
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+    fn most_common_char(&self) -> Option<(char, usize)> {
+        let this = self.borrow();
+        let freqs = this.char_frequency();
+
+        let mut best: Option<(char, usize)> = None;
+        for c in this.chars() {
+            let count = freqs[&c];
+            match best {
+                Some((_, b_count)) if b_count >= count => {}
+                _ => best = Some((c, count)),
+            }
+        }
+        best
+    }
+
+    /// Pads the string on the left with `how_much` additional spaces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!(
+    ///     "what\n  the\n    hall".left_pad(4),
+    ///     "    what\n      the\n        hall"
+    /// );
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn left_pad(&self, how_much: usize) -> String {
+        use alloc::string::ToString;
+        self.left_padder(how_much).to_string()
+    }
+    /// Returns a value that pads the string on the left with `how_much` additional
+    /// spaces in its `Display` impl.
+    ///
+    /// Use this to avoid allocating an extra string.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(not(feature = "alloc"), doc = " ```ignore")]
+    #[cfg_attr(feature = "alloc", doc = " ```rust")]
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!(
+    ///     "what\n  the\n    hall".left_pad(4).to_string(),
+    ///     "    what\n      the\n        hall"
+    /// );
+    /// ```
+    ///
+    fn left_padder<'a>(&'a self, how_much: usize) -> LeftPadder<'a> {
+        LeftPadder::new(self.borrow(), how_much)
+    }
+
+    /// Pads every line of the string on the right with spaces,
+    /// so that it's at least `width` chars long.
+    ///
+    /// Lines that only contain whitespace are not padded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!(
+    ///     "what\n  the\n    hall".right_pad(6),
+    ///     "what  \n  the \n    hall"
+    /// );
+    ///
+    /// // Trailing newlines are preserved, and blank lines are left as is.
+    /// assert_eq!("foo\n\nbarbaz\n".right_pad(5), "foo  \n\nbarbaz\n");
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn right_pad(&self, width: usize) -> String {
+        use alloc::string::ToString;
+        self.right_padder(width).to_string()
+    }
+    /// Returns a value that pads every line of the string on the right with spaces,
+    /// so that it's at least `width` chars long, in its `Display` impl.
+    ///
+    /// Use this to avoid allocating an extra string.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(not(feature = "alloc"), doc = " ```ignore")]
+    #[cfg_attr(feature = "alloc", doc = " ```rust")]
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!(
+    ///     format!("[{}]", "foo\nbar baz".right_padder(5)),
+    ///     "[foo  \nbar baz]"
+    /// );
+    /// ```
+    ///
+    fn right_padder<'a>(&'a self, width: usize) -> RightPadder<'a> {
+        RightPadder::new(self.borrow(), width)
+    }
+
+    /// Pads every line of the string on both sides with spaces.
+    ///
+    /// This adds `left` spaces at the start of every line,
+    /// then pads every line on the right so that it's at least `right` chars long
+    /// (counting the spaces added on the left).
+    ///
+    /// Lines that only contain whitespace are not padded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!(
+    ///     "foo\nbarbaz\n\nqux\n".pad_sides(2, 7),
+    ///     "  foo  \n  barbaz\n\n  qux  \n"
+    /// );
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn pad_sides(&self, left: usize, right: usize) -> String {
+        use std_::fmt::Write;
+
+        let mut out = String::new();
+        for (i, line) in self.borrow().split('\n').enumerate() {
+            if i != 0 {
+                out.push('\n');
+            }
+            if line.trim().is_empty() {
+                out.push_str(line);
+            } else {
+                let right = RightPadder::new(line, right.saturating_sub(left));
+                let _ = write!(out, "{:left$}{}", "", right, left = left);
+            }
+        }
+        out
+    }
+
+    /// Word-wraps the string so that no line is longer than `width` chars,
+    /// prefixing every line with `indent`.
+    ///
+    /// The chars in `indent` count towards `width`.
+    /// Words that don't fit in a line on their own are split across lines.
+    /// If `indent` is at least `width` chars long, every line has one char after the indent.
+    ///
+    /// Every line in the string is wrapped separately,
+    /// and whitespace between words is collapsed into a single space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let help = "Prints the help message for the subcommand, then exits.";
+    ///
+    /// assert_eq!(
+    ///     help.wrap_indent(24, "    "),
+    ///     "    Prints the help\n    message for the\n    subcommand, then\n    exits."
+    /// );
+    ///
+    /// assert_eq!("foo bar\n\nbaz".wrap_indent(80, "> "), "> foo bar\n\n> baz");
+    ///
+    /// assert_eq!("abcdefgh".wrap_indent(5, "- "), "- abc\n- def\n- gh");
+    ///
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn wrap_indent(&self, width: usize, indent: &str) -> String {
+        let budget = cmp::max(width.saturating_sub(indent.chars().count()), 1);
+        let mut out = String::new();
+
+        for (i, line) in self.borrow().lines().enumerate() {
+            if i != 0 {
+                out.push('\n');
+            }
+            let mut line_len = 0;
+            for mut word in line.split_whitespace() {
+                let mut word_len = word.chars().count();
+                if line_len != 0 && line_len + 1 + word_len <= budget {
+                    out.push(' ');
+                    out.push_str(word);
+                    line_len += 1 + word_len;
+                    continue;
+                }
+                loop {
+                    if line_len != 0 {
+                        out.push('\n');
+                    }
+                    out.push_str(indent);
+                    if word_len <= budget {
+                        out.push_str(word);
+                        line_len = word_len;
+                        break;
+                    }
+                    let split_at = word.nth_char_index(budget);
+                    out.push_str(&word[..split_at]);
+                    word = &word[split_at..];
+                    word_len -= budget;
+                    line_len = budget;
+                }
+            }
+        }
+        out
+    }
+    /// The indentation of the first line.
+    ///
+    /// This considers lines that only contains whitespace to have as 
+    /// much indentation as they're long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!("".line_indentation(), 0);
+    /// assert_eq!("    ".line_indentation(), 4);
+    /// assert_eq!("    \n      word".line_indentation(), 4);
+    /// assert_eq!("    \nword".line_indentation(), 4);
+    ///
+    /// ```
+    ///
+    #[cfg(any(core_str_methods, feature = "alloc"))]
+    fn line_indentation(&self) -> usize {
+        let this = self.borrow().lines().next().unwrap_or("");
+        this.len() - this.trim_start().len()
+    }
+
+    /// The minimum indentation of the string, ignoring lines that only contain whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// assert_eq!("".min_indentation(), 0);
+    /// assert_eq!("    ".min_indentation(), 0);
+    /// assert_eq!("    \nf".min_indentation(), 0);
+    /// assert_eq!("    \n      word".min_indentation(), 6);
     /// assert_eq!("    \n word".min_indentation(), 1);
     /// assert_eq!("    \n\nword".min_indentation(), 0);
     ///
@@ -646,12 +1783,96 @@ pub trait StringExt: Borrow<str> {
             .max()
             .unwrap_or(0)
     }
+
+    /// Removes the common leading indentation from every line of the string.
+    ///
+    /// This removes [`min_indentation`](#method.min_indentation) bytes 
+    /// from the start of every line,
+    /// lines that only contain whitespace become empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_extensions::StringExt;
+    ///
+    /// let text = "
+    ///         fn main() {
+    ///             println!(\"hello\");
+    ///         }
+    /// ";
+    ///
+    /// assert_eq!(
+    ///     text.dedent(),
+    ///     "\nfn main() {\n    println!(\"hello\");\n}\n",
+    /// );
+    ///
+    /// assert_eq!("  foo\n    \n    bar".dedent(), "foo\n\n  bar");
+    ///
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    fn dedent(&self) -> String {
+        use alloc::string::ToString;
+        self.dedenter().to_string()
+    }
+
+    /// Returns a value that removes the common leading indentation 
+    /// from every line of the string in its `Display` impl.
+    ///
+    /// Use this to avoid allocating an extra string.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(not(feature = "alloc"), doc = " ```ignore")]
+    #[cfg_attr(feature = "alloc", doc = " ```rust")]
+    /// use core_extensions::StringExt;
+    ///
+    /// let text = "
+    ///     foo
+    ///       bar
+    ///     baz";
+    ///
+    /// assert_eq!(format!("[{}]", text.dedenter()), "[\nfoo\n  bar\nbaz]");
+    ///
+    /// ```
+    ///
+    #[cfg(any(core_str_methods, feature = "alloc"))]
+    fn dedenter<'a>(&'a self) -> Dedenter<'a> {
+        Dedenter::new(self.borrow())
+    }
 }
 
 impl<T: ?Sized> StringExt for T where T: Borrow<str> {}
 
 //----------------------------------------------------------------------------------------
 
+// Approximates the amount of terminal columns that `c` takes,
+// see `StringExt::split_at_display_width` for the heuristic.
+fn char_display_width(c: char) -> usize {
+    match c as u32 {
+        0x00..=0x1F | 0x7F..=0x9F => 0,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x2FFFD
+        | 0x30000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+//---------------------------------------------------------------------------------------
+
 /// Add padding to a string in its `Display` impl.
 /// 
 /// # Example
@@ -705,6 +1926,165 @@ impl<'a> fmt::Display for LeftPadder<'a> {
     }
 }
 
+/// Pads every line of a string on the right in its `Display` impl,
+/// so that each line is at least `width` chars long.
+///
+/// Lines that only contain whitespace are not padded.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use core_extensions::strings::RightPadder;
+/// 
+/// assert_eq!(RightPadder::new("foo\n bar", 0).to_string(), "foo\n bar");
+/// assert_eq!(RightPadder::new("foo\n bar", 4).to_string(), "foo \n bar");
+/// assert_eq!(RightPadder::new("foo\n bar", 6).to_string(), "foo   \n bar  ");
+/// assert_eq!(RightPadder::new("foo\n  \nbar\n", 4).to_string(), "foo \n  \nbar \n");
+/// 
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RightPadder<'a> {
+    string: &'a str,
+    width: usize,
+}
+
+impl<'a> RightPadder<'a> {
+    /// Constructs a RightPadder
+    pub fn new(string: &'a str, width: usize) -> Self {
+        Self { string, width }
+    }
+}
+
+impl<'a> fmt::Display for RightPadder<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+        use std_::fmt::Write;
+        for line in self.string.split('\n') {
+            if !first {
+                f.write_char('\n')?;
+            }
+            const SPACES: &str = "                                ";
+
+            let (line, cr) = match line.len().checked_sub(1) {
+                Some(last) if line.as_bytes()[last] == b'\r' => (&line[..last], "\r"),
+                _ => (line, ""),
+            };
+
+            let has_non_whitespace = line.contains(|c: char| !c.is_whitespace());
+            let mut pad = if has_non_whitespace { 
+                self.width.saturating_sub(line.chars().count())
+            } else {
+                0
+            };
+            
+            fmt::Display::fmt(line, f)?;
+            while let Some(next) = pad.checked_sub(SPACES.len()) {
+                f.write_str(SPACES)?;
+                pad = next;
+            }
+            f.write_str(&SPACES[..pad])?;
+            f.write_str(cr)?;
+
+            first = false;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned by [`StringExt::unescape_basic`] when the string 
+/// contains an invalid escape sequence.
+///
+/// [`StringExt::unescape_basic`]: ../trait.StringExt.html#method.unescape_basic
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UnescapeError {
+    /// The string ended with an unescaped backslash.
+    DanglingBackslash {
+        /// The byte index of the backslash.
+        index: usize,
+    },
+    /// A backslash was followed by a char that can't be escaped.
+    InvalidEscape {
+        /// The byte index of the backslash.
+        index: usize,
+        /// The char after the backslash.
+        found: char,
+    },
+}
+
+impl fmt::Display for UnescapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UnescapeError::DanglingBackslash { index } => {
+                write!(f, "unescaped backslash at the end of the string, at byte {}", index)
+            }
+            UnescapeError::InvalidEscape { index, found } => {
+                write!(f, "invalid escape sequence `\\{}` at byte {}", found, index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std_::error::Error for UnescapeError {}
+
+//---------------------------------------------------------------------------------------
+
+/// Removes the common leading indentation from every line of a string 
+/// in its `Display` impl.
+///
+/// Lines that only contain whitespace are written as empty lines.
+/// 
+/// # Example
+/// 
+#[cfg_attr(not(feature = "alloc"), doc = " ```ignore")]
+#[cfg_attr(feature = "alloc", doc = " ```rust")]
+/// use core_extensions::strings::Dedenter;
+/// 
+/// assert_eq!(Dedenter::new("  foo\n   bar").to_string(), "foo\n bar");
+/// assert_eq!(Dedenter::new("  foo\n \n  bar\n").to_string(), "foo\n\nbar\n");
+/// assert_eq!(Dedenter::new("foo\n  bar").to_string(), "foo\n  bar");
+/// 
+/// ```
+#[cfg(any(core_str_methods, feature = "alloc"))]
+#[derive(Clone, Copy, Debug)]
+pub struct Dedenter<'a> {
+    string: &'a str,
+    indentation: usize,
+}
+
+#[cfg(any(core_str_methods, feature = "alloc"))]
+impl<'a> Dedenter<'a> {
+    /// Constructs a Dedenter, computing the indentation to remove.
+    pub fn new(string: &'a str) -> Self {
+        Self {
+            string,
+            indentation: string.min_indentation(),
+        }
+    }
+}
+
+#[cfg(any(core_str_methods, feature = "alloc"))]
+impl<'a> fmt::Display for Dedenter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use std_::fmt::Write;
+        for (i, line) in self.string.split('\n').enumerate() {
+            if i != 0 {
+                f.write_char('\n')?;
+            }
+            if line.trim_start().is_empty() {
+                if line.ends_with('\r') {
+                    f.write_char('\r')?;
+                }
+                continue;
+            }
+            // Indentation that isn't ASCII whitespace could make this a non-char-boundary
+            let start = line.left_char_boundary(self.indentation);
+            f.write_str(&line[start..])?;
+        }
+        Ok(())
+    }
+}
+
 //---------------------------------------------------------------------------------------
 
 #[cfg(test)]
@@ -725,6 +2105,237 @@ mod tests {
         assert_eq!("\n\nfoo".left_pad(4), "\n\n    foo");
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_right_pad() {
+        let s = "what\n  the\n    hall";
+        assert_eq!(s.right_pad(0), s);
+        assert_eq!(s.right_pad(4), s);
+        assert_eq!(s.right_pad(7), "what   \n  the  \n    hall");
+
+        assert_eq!("\n\nfoo\n".right_pad(4), "\n\nfoo \n");
+        assert_eq!("foo\r\nbar\r\n".right_pad(4), "foo \r\nbar \r\n");
+        assert_eq!("ñu\n速度".right_pad(3), "ñu \n速度 ");
+        assert_eq!("foo".right_pad(40).len(), 40);
+        assert_eq!("".right_pad(4), "");
+
+        assert_eq!("foo\n bar".pad_sides(1, 5), " foo \n  bar");
+        assert_eq!("foo\r\n  \r\n".pad_sides(1, 5), " foo \r\n  \r\n");
+        assert_eq!("foo".pad_sides(40, 0).len(), 43);
+        assert_eq!("foo\n\nbar\n".pad_sides(0, 4), "foo \n\nbar \n");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_dedent() {
+        assert_eq!("".dedent(), "");
+        assert_eq!("foo".dedent(), "foo");
+        assert_eq!("    foo".dedent(), "foo");
+        assert_eq!("    foo\n".dedent(), "foo\n");
+        assert_eq!("  foo\n    bar\n   baz".dedent(), "foo\n  bar\n baz");
+        assert_eq!("\tfoo\n\t\tbar".dedent(), "foo\n\tbar");
+        assert_eq!("  foo\r\n    bar\r\n".dedent(), "foo\r\n  bar\r\n");
+
+        // whitespace-only lines are emptied, and don't count for the indentation
+        assert_eq!("\n      \n    foo\n  \n      bar\n".dedent(), "\n\nfoo\n\n  bar\n");
+        assert_eq!("    foo\n  \r\n    bar".dedent(), "foo\n\r\nbar");
+
+        // non-ASCII whitespace doesn't cause a panic
+        assert_eq!("\u{3000}foo\n  bar".dedent(), "\u{3000}foo\nbar");
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        let text = "ab速度惊人";
+        for n in 0..9 {
+            assert_eq!(text.truncate_chars(n), text.first_chars(n));
+        }
+        assert_eq!(text.truncate_chars(3), "ab速");
+        assert_eq!(text.truncate_chars(6), text);
+        assert_eq!("".truncate_chars(2), "");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_truncate_chars_with() {
+        let text = "ab速度惊人";
+        for n in 0..6 {
+            let out = text.truncate_chars_with(n, "..");
+            if let Cow::Borrowed(_) = out {
+                panic!("expected an owned string for n = {}", n);
+            }
+            assert_eq!(out, format!("{}..", text.truncate_chars(n)));
+        }
+        for n in 6..9 {
+            let out = text.truncate_chars_with(n, "..");
+            if let Cow::Owned(_) = out {
+                panic!("expected a borrowed string for n = {}", n);
+            }
+            assert_eq!(out, text);
+        }
+
+        assert_eq!(text.truncate_chars_with(3, ""), "ab速");
+        assert_eq!("".truncate_chars_with(0, ".."), "");
+        assert_eq!("".truncate_chars_with(5, ".."), "");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_escape_basic() {
+        let cases = [
+            ("", ""),
+            ("foo", "foo"),
+            ("line\nline", "line\\nline"),
+            ("\tindented", "\\tindented"),
+            ("\"quoted\"", "\\\"quoted\\\""),
+            ("back\\slash", "back\\\\slash"),
+            ("\\n", "\\\\n"),
+            ("速度\n惊人", "速度\\n惊人"),
+        ];
+        for &(raw, escaped) in &cases {
+            assert_eq!(raw.escape_basic(), escaped);
+            assert_eq!(escaped.unescape_basic().as_ref().map(|x| &**x), Ok(raw));
+        }
+
+        assert_eq!("\\".unescape_basic(), Err(UnescapeError::DanglingBackslash { index: 0 }));
+        assert_eq!("速\\".unescape_basic(), Err(UnescapeError::DanglingBackslash { index: 3 }));
+        assert_eq!(
+            "速\\度".unescape_basic(),
+            Err(UnescapeError::InvalidEscape { index: 3, found: '度' }),
+        );
+        assert_eq!(
+            "\\r".unescape_basic(),
+            Err(UnescapeError::InvalidEscape { index: 0, found: 'r' }),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_title_case_by() {
+        let underscore = |c: char| c == '_';
+
+        assert_eq!("".title_case_by(underscore), "");
+        assert_eq!("hello_world".title_case_by(underscore), "Hello_World");
+        assert_eq!("_hello__world_".title_case_by(underscore), "_Hello__World_");
+        assert_eq!("Hello_WORLD".title_case_by(underscore), "Hello_WORLD");
+        assert_eq!("hello world".title_case_by(underscore), "Hello world");
+        assert_eq!("ñandú_ßa".title_case_by(underscore), "Ñandú_SSa");
+        assert_eq!("hello_world".title_case_by(|_| false), "Hello_world");
+        assert_eq!("abc".title_case_by(|_| true), "ABC");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_wrap_indent() {
+        let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, \
+                    sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.\n\
+                    \n\
+                    Ñandú  über   naïve, supercalifragilisticexpialidocious.";
+
+        for &indent in &["", "  ", "////", "ññ "] {
+            for width in 1..40 {
+                let wrapped = text.wrap_indent(width, indent);
+                for line in wrapped.lines().filter(|l| !l.is_empty()) {
+                    assert!(line.starts_with(indent), "{:?}", line);
+                    assert!(
+                        line.chars().count() <= cmp::max(width, indent.chars().count() + 1),
+                        "{} {:?}",
+                        width,
+                        line,
+                    );
+                }
+            }
+        }
+
+        assert_eq!("".wrap_indent(10, "  "), "");
+        assert_eq!("a b c d".wrap_indent(5, ""), "a b c\nd");
+        assert_eq!("a b c d".wrap_indent(6, " "), " a b c\n d");
+        assert_eq!("ñu".wrap_indent(1, "--"), "--ñ\n--u");
+    }
+
+    #[test]
+    fn test_split_at_display_width() {
+        assert_eq!("hello".split_at_display_width(0), ("", "hello"));
+        assert_eq!("hello".split_at_display_width(2), ("he", "llo"));
+        assert_eq!("hello".split_at_display_width(5), ("hello", ""));
+
+        assert_eq!("日本語".split_at_display_width(2), ("日", "本語"));
+        assert_eq!("日本語".split_at_display_width(3), ("日", "本語"));
+        assert_eq!("日本語".split_at_display_width(6), ("日本語", ""));
+        assert_eq!("한국어".split_at_display_width(4), ("한국", "어"));
+        assert_eq!("ｆｕｌｌ".split_at_display_width(4), ("ｆｕ", "ｌｌ"));
+
+        assert_eq!("ab日c".split_at_display_width(3), ("ab", "日c"));
+        assert_eq!("ab日c".split_at_display_width(4), ("ab日", "c"));
+        assert_eq!("ñu日".split_at_display_width(2), ("ñu", "日"));
+        assert_eq!("a\tb".split_at_display_width(2), ("a\tb", ""));
+    }
+
+    #[test]
+    fn test_common_prefix_suffix() {
+        assert_eq!("".common_prefix_len(""), 0);
+        assert_eq!("".common_suffix_len("foo"), 0);
+        assert_eq!("foo".common_prefix_len("foo"), 3);
+        assert_eq!("foo".common_suffix_len("foo"), 3);
+        assert_eq!("abc".common_prefix_len("abd"), 2);
+        assert_eq!("abc".common_suffix_len("xbc"), 2);
+
+        // "é" is [0xC3, 0xA9] and "è" is [0xC3, 0xA8]
+        assert_eq!("aé".common_prefix_len("aè"), 1);
+        assert_eq!("aé".common_prefix("aè"), "a");
+        assert_eq!("ñé".common_prefix_len("ñè"), 2);
+        assert_eq!("ñé".common_prefix("ñè"), "ñ");
+
+        // "Ā" is [0xC4, 0x80] and "ā" is [0xC4, 0x81], "Ȁ" is [0xC8, 0x80]
+        assert_eq!("Āz".common_suffix_len("Ȁz"), 1);
+        assert_eq!("Āz".common_suffix("Ȁz"), "z");
+        assert_eq!("Āñ".common_suffix("āñ"), "ñ");
+
+        let this = "hello world";
+        let prefix = this.common_prefix("hello there");
+        assert_eq!(prefix, "hello ");
+        assert_eq!(prefix.as_ptr(), this.as_ptr());
+
+        let suffix = this.common_suffix("brave new world");
+        assert_eq!(suffix, " world");
+        assert_eq!(suffix.as_ptr(), this[5..].as_ptr());
+    }
+
+    #[test]
+    fn test_parse_key_value() {
+        assert_eq!("key = value".parse_key_value('='), Some(("key", "value")));
+        assert_eq!("key=value".parse_key_value('='), Some(("key", "value")));
+        assert_eq!("\tkey\t=\tvalue\n".parse_key_value('='), Some(("key", "value")));
+        assert_eq!("a = b = c".parse_key_value('='), Some(("a", "b = c")));
+        assert_eq!("名前 → 値".parse_key_value('→'), Some(("名前", "値")));
+        assert_eq!("=".parse_key_value('='), Some(("", "")));
+
+        assert_eq!("key value".parse_key_value('='), None);
+        assert_eq!("key = value".parse_key_value(':'), None);
+    }
+
+    #[test]
+    fn test_line_col_at() {
+        assert_eq!("".line_col_at(0), (1, 0));
+        assert_eq!("".line_col_at(5), (1, 0));
+
+        let text = "añb\nçé\n\nx";
+        assert_eq!(text.line_col_at(0), (1, 0));
+        assert_eq!(text.line_col_at(1), (1, 1));
+        // This index is inside of 'ñ'
+        assert_eq!(text.line_col_at(2), (1, 1));
+        assert_eq!(text.line_col_at(3), (1, 2));
+        assert_eq!(text.line_col_at(4), (1, 3));
+        assert_eq!(text.line_col_at(5), (2, 0));
+        assert_eq!(text.line_col_at(7), (2, 1));
+        assert_eq!(text.line_col_at(9), (2, 2));
+        assert_eq!(text.line_col_at(10), (3, 0));
+        assert_eq!(text.line_col_at(11), (4, 0));
+        assert_eq!(text.line_col_at(12), (4, 1));
+        assert_eq!(text.line_col_at(13), (4, 1));
+        assert_eq!(text.line_col_at(1000), (4, 1));
+    }
+
     #[test]
     fn test_right_char_boundary() {
         let word = "niño";
@@ -776,4 +2387,64 @@ mod tests {
             "niño"
         );
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_char_indices_rev() {
+        for word in &["", "a", "niño", "foo 効 ", "υιός 😀 x"] {
+            let mut forward = word.char_indices().collect::<Vec<_>>();
+            forward.reverse();
+            assert_eq!(word.char_indices_rev().collect::<Vec<_>>(), forward);
+
+            let mut backward = word.char_indices_rev().rev().collect::<Vec<_>>();
+            backward.reverse();
+            assert_eq!(backward, forward);
+
+            for &(i, c) in &forward {
+                assert_eq!(word[i..].chars().next(), Some(c));
+            }
+        }
+
+        let word = "ab効";
+        let mut iter = word.char_indices_rev();
+        assert_eq!(iter.size_hint(), word.char_indices().size_hint());
+        assert_eq!(iter.next(), Some((2, '効')));
+        assert_eq!(iter.as_str(), "ab");
+        assert_eq!(iter.clone().count(), 2);
+        assert_eq!(iter.clone().last(), Some((0, 'a')));
+        assert_eq!(iter.next(), Some((1, 'b')));
+        assert_eq!(iter.next(), Some((0, 'a')));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_wrap_words() {
+        fn wrap(s: &str, width: usize) -> Vec<&str> {
+            s.wrap_words(width).collect()
+        }
+
+        assert_eq!(wrap("", 5), Vec::<&str>::new());
+        assert_eq!(wrap("   \t\n ", 5), Vec::<&str>::new());
+        assert_eq!(wrap("hello", 5), vec!["hello"]);
+        assert_eq!(wrap("hello world", 5), vec!["hello", "world"]);
+        assert_eq!(wrap("hello world", 11), vec!["hello world"]);
+        assert_eq!(wrap("hello world", 100), vec!["hello world"]);
+        assert_eq!(wrap("a b c d e", 3), vec!["a b", "c d", "e"]);
+        assert_eq!(wrap("a b c d e", 4), vec!["a b", "c d", "e"]);
+        assert_eq!(wrap("a  b   c", 4), vec!["a  b", "c"]);
+        assert_eq!(wrap("foo \n\t  bar\n", 4), vec!["foo", "bar"]);
+
+        assert_eq!(wrap("abcdefgh", 3), vec!["abc", "def", "gh"]);
+        assert_eq!(wrap("ab abcdefgh ab", 3), vec!["ab", "abc", "def", "gh", "ab"]);
+        assert_eq!(wrap("ñññññ ñ", 2), vec!["ññ", "ññ", "ñ", "ñ"]);
+        assert_eq!(wrap("ab c", 0), vec!["a", "b", "c"]);
+
+        let mut iter = "hello there world".wrap_words(11);
+        assert_eq!(iter.next(), Some("hello there"));
+        assert_eq!(iter.as_str(), " world");
+        assert_eq!(iter.next(), Some("world"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
 }
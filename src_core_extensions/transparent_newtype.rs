@@ -9,6 +9,7 @@ use alloc::{
 };
 
 use std_::mem;
+use std_::pin::Pin;
 
 use crate::utils::transmute_ignore_size;
 
@@ -172,6 +173,75 @@ pub trait TransparentNewtypeExt: TransparentNewtype {
         unsafe { &mut *Self::from_inner_raw_mut(v) }
     }
 
+    /// Converts `Pin<&Self::Inner>` to a `Pin<&Self>`.
+    /// 
+    /// # Safety
+    /// 
+    /// `Self` must pin its `Self::Inner` field structurally,
+    /// which requires all of these:
+    /// 
+    /// - `Self` must only implement `Unpin` if `Self::Inner` does.
+    /// 
+    /// - The `Drop` impl of `Self` (if any) must not move out of the field.
+    /// 
+    /// - `Self` must not provide any way to move out of the field 
+    ///   given a pinned reference to `Self`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use core_extensions::TransparentNewtypeExt;
+    /// 
+    /// use std::mem::ManuallyDrop;
+    /// use std::pin::Pin;
+    /// 
+    /// let inner = Box::pin(5);
+    /// 
+    /// // `ManuallyDrop` only implements `Unpin` if its field does,
+    /// // and doesn't otherwise move its field.
+    /// let wrapper: Pin<&ManuallyDrop<u32>> = unsafe{
+    ///     ManuallyDrop::from_inner_pin_ref(inner.as_ref())
+    /// };
+    /// assert_eq!(*wrapper, ManuallyDrop::new(5));
+    /// 
+    /// ```
+    #[inline(always)]
+    unsafe fn from_inner_pin_ref(v: Pin<&Self::Inner>) -> Pin<&Self> {
+        v.map_unchecked(|x| Self::from_inner_ref(x))
+    }
+
+    /// Converts `Pin<&mut Self::Inner>` to a `Pin<&mut Self>`.
+    /// 
+    /// # Safety
+    /// 
+    /// This has the same safety requirements as 
+    /// [`from_inner_pin_ref`](#method.from_inner_pin_ref).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use core_extensions::TransparentNewtypeExt;
+    /// 
+    /// use std::num::Wrapping;
+    /// use std::pin::Pin;
+    /// 
+    /// let mut inner = Box::pin(3);
+    /// 
+    /// // `Wrapping` only implements `Unpin` if its field does,
+    /// // and doesn't otherwise move its field.
+    /// let mut wrapper: Pin<&mut Wrapping<u32>> = unsafe{
+    ///     Wrapping::from_inner_pin_mut(inner.as_mut())
+    /// };
+    /// *wrapper += Wrapping(5);
+    /// 
+    /// assert_eq!(*inner, 8);
+    /// 
+    /// ```
+    #[inline(always)]
+    unsafe fn from_inner_pin_mut(v: Pin<&mut Self::Inner>) -> Pin<&mut Self> {
+        v.map_unchecked_mut(|x| Self::from_inner_mut(x))
+    }
+
     /// Converts `Box<Self::Inner>` to a `Box<Self>` without allocating.
     /// 
     /// # Example
@@ -5,6 +5,184 @@ use crate::TypeIdentity;
 use std_::marker::PhantomData;
 
 
+/// Asserts that all the types passed to it are the same type,
+/// failing to compile otherwise.
+///
+/// This macro can be used both as an item and as a statement,
+/// but it can't refer to generic parameters,
+/// [`AssertEq`] can be used for that.
+///
+/// [`AssertEq`]: ./type_asserts/struct.AssertEq.html
+///
+/// # Example
+///
+/// ```
+/// use core_extensions::assert_type_eq;
+///
+/// trait ElemTy {
+///     type Elem;
+/// }
+///
+/// impl<A> ElemTy for (A,) {
+///     type Elem = A;
+/// }
+///
+/// assert_type_eq!(u32, <(u32,) as ElemTy>::Elem);
+///
+/// fn main() {
+///     type Item = <std::vec::IntoIter<&'static str> as Iterator>::Item;
+///     
+///     assert_type_eq!(&'static str, Item, <(Item,) as ElemTy>::Elem);
+/// }
+///
+/// ```
+///
+/// # Non-compiling
+///
+/// ```compile_fail
+/// use core_extensions::assert_type_eq;
+///
+/// assert_type_eq!(u32, ());
+///
+/// ```
+///
+/// ```compile_fail
+/// use core_extensions::assert_type_eq;
+///
+/// assert_type_eq!(u32, u32, u64);
+///
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "type_asserts")))]
+#[macro_export]
+macro_rules! assert_type_eq {
+    ($first:ty $(, $rest:ty)+ $(,)*) => {
+        $(
+            const _: $crate::type_asserts::AssertEq<$first, $rest> = 
+                $crate::type_asserts::AssertEq::NEW;
+        )+
+    };
+}
+
+
+/// Asserts that a type implements all the listed traits,
+/// failing to compile otherwise.
+///
+/// This macro can be used both as an item and as a statement,
+/// but it can't refer to generic parameters.
+///
+/// # Example
+///
+/// ```
+/// use core_extensions::assert_impl_all;
+///
+/// use std::fmt::Debug;
+///
+/// #[derive(Debug, Clone)]
+/// struct Token(String);
+///
+/// assert_impl_all!(Token: Send, Sync, Clone, Debug);
+/// assert_impl_all!(u32: Copy, Default, Into<u64>);
+///
+/// fn main() {
+///     assert_impl_all!(Vec<Token>: Clone, IntoIterator<Item = Token>);
+/// }
+///
+/// ```
+///
+/// # Non-compiling
+///
+/// ```compile_fail
+/// use core_extensions::assert_impl_all;
+///
+/// assert_impl_all!(String: Clone, Copy);
+///
+/// ```
+///
+/// ```compile_fail
+/// use core_extensions::assert_impl_all;
+///
+/// assert_impl_all!(std::rc::Rc<u32>: Send);
+///
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "type_asserts")))]
+#[macro_export]
+macro_rules! assert_impl_all {
+    ($type:ty: $($trait:path),+ $(,)*) => {
+        const _: fn() = || {
+            fn assert_impl_all<T: ?Sized $(+ $trait)+>() {}
+            assert_impl_all::<$type>();
+        };
+    };
+}
+
+
+/// Asserts that a type implements none of the listed traits,
+/// failing to compile if it implements any of them.
+///
+/// This macro can be used both as an item and as a statement,
+/// but it can't refer to generic parameters.
+///
+/// # Example
+///
+/// ```
+/// use core_extensions::assert_not_impl_any;
+///
+/// use std::{cell::Cell, rc::Rc};
+///
+/// assert_not_impl_any!(String: Copy);
+/// assert_not_impl_any!(Rc<u32>: Send, Sync);
+/// assert_not_impl_any!(Cell<u32>: Sync, std::fmt::Display);
+///
+/// fn main() {
+///     assert_not_impl_any!(*const u8: Send);
+/// }
+///
+/// ```
+///
+/// # Non-compiling
+///
+/// ```compile_fail
+/// use core_extensions::assert_not_impl_any;
+///
+/// assert_not_impl_any!(u32: Copy);
+///
+/// ```
+///
+/// ```compile_fail
+/// use core_extensions::assert_not_impl_any;
+///
+/// assert_not_impl_any!(String: Copy, Send, std::fmt::Display);
+///
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "type_asserts")))]
+#[macro_export]
+macro_rules! assert_not_impl_any {
+    ($type:ty: $($trait:path),+ $(,)*) => {
+        const _: fn() = || {
+            // If `$type` implements any of the traits,
+            // there are multiple impls of `AmbiguousIfImpl<_>` for it,
+            // so the type of `_` can't be inferred.
+            trait AmbiguousIfImpl<A> {
+                fn some_item() {}
+            }
+
+            impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+
+            $({
+                #[allow(dead_code)]
+                struct Invalid;
+
+                impl<T: ?Sized + $trait> AmbiguousIfImpl<Invalid> for T {}
+            })+
+
+            let _ = <$type as AmbiguousIfImpl<_>>::some_item;
+        };
+    };
+}
+
 /// Asserts that its 2 type parameters are the same type.
 ///
 /// This assertion is done on the type level,
@@ -179,6 +179,70 @@ impl Boolean for False {
     const VALUE: bool = false;
 }
 
+#[cfg(feature = "rust_1_51")]
+macro_rules! declare_from_const_bool {
+    () => {
+        /// Converts a `const B: bool` parameter to the equivalent [`Boolean`] type,
+        /// through the [`ToBoolean`] trait.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use core_extensions::type_level_bool::{Boolean, False, FromConstBool, ToBoolean, True};
+        ///
+        /// type TypeOf<const B: bool> = <FromConstBool<B> as ToBoolean>::Type;
+        ///
+        /// let _: TypeOf<true> = True;
+        /// let _: TypeOf<false> = False;
+        ///
+        /// assert_eq!(TypeOf::<true>::VALUE, true);
+        /// assert_eq!(TypeOf::<false>::VALUE, false);
+        ///
+        /// fn to_runtime<const B: bool>() -> bool
+        /// where
+        ///     FromConstBool<B>: ToBoolean,
+        /// {
+        ///     <FromConstBool<B> as ToBoolean>::Type::VALUE
+        /// }
+        ///
+        /// assert_eq!(to_runtime::<true>(), true);
+        /// assert_eq!(to_runtime::<false>(), false);
+        ///
+        /// ```
+        ///
+        /// [`Boolean`]: ./trait.Boolean.html
+        /// [`ToBoolean`]: ./trait.ToBoolean.html
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct FromConstBool<const B: bool>;
+
+        /// Gets the [`Boolean`] type equivalent to `Self`.
+        ///
+        /// For examples look at the docs for [`FromConstBool`].
+        ///
+        /// [`Boolean`]: ./trait.Boolean.html
+        /// [`FromConstBool`]: ./struct.FromConstBool.html
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_51")))]
+        pub trait ToBoolean {
+            /// The [`Boolean`] type equivalent to `Self`.
+            ///
+            /// [`Boolean`]: ./trait.Boolean.html
+            type Type: Boolean;
+        }
+
+        impl ToBoolean for FromConstBool<true> {
+            type Type = True;
+        }
+
+        impl ToBoolean for FromConstBool<false> {
+            type Type = False;
+        }
+    };
+}
+
+#[cfg(feature = "rust_1_51")]
+declare_from_const_bool! {}
+
 mod internals {
     #[cfg(feature = "const_default")]
     use crate::ConstDefault;
@@ -291,3 +355,166 @@ pub type Or<L, R> = <L as ops::BitOr<R>>::Output;
 ///     assert_eq!(Xor::<False, False>::VALUE, false);
 ///
 pub type Xor<L, R> = <L as ops::BitXor<R>>::Output;
+
+/// A wrapper that only allows appending to `T` when `B` is [`True`].
+///
+/// The wrapped value can always be read through `Deref`,
+/// but it can't be mutated in any other way through this wrapper.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::type_level_bool::{AppendOnlyIfTrue, False, True};
+///
+/// let mut log = AppendOnlyIfTrue::new(Vec::new(), True);
+/// log.push("started");
+/// log.extend(vec!["loaded config", "connected"]);
+/// assert_eq!(*log, ["started", "loaded config", "connected"]);
+///
+/// let log: AppendOnlyIfTrue<Vec<&str>, False> = log.freeze();
+///
+/// // Doesn't compile, because appending requires `B` to be `True`
+/// // log.push("stopped");
+///
+/// assert_eq!(log.len(), 3);
+/// assert_eq!(log.into_inner(), ["started", "loaded config", "connected"]);
+///
+/// ```
+///
+/// Appending to a frozen value is a compile-time error:
+///
+/// ```compile_fail
+/// use core_extensions::type_level_bool::{AppendOnlyIfTrue, False};
+///
+/// let mut log = AppendOnlyIfTrue::new(Vec::new(), False);
+/// log.push(3);
+/// ```
+///
+/// [`True`]: ./struct.True.html
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AppendOnlyIfTrue<T, B> {
+    value: T,
+    appendable: B,
+}
+
+impl<T, B: Boolean> AppendOnlyIfTrue<T, B> {
+    /// Constructs an `AppendOnlyIfTrue`,
+    /// where `appendable` determines whether `value` can be appended to.
+    #[inline]
+    pub fn new(value: T, appendable: B) -> Self {
+        Self { value, appendable }
+    }
+
+    /// Unwraps the value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Gets the [`Boolean`] that determines whether the value can be appended to.
+    ///
+    /// [`Boolean`]: ./trait.Boolean.html
+    #[inline]
+    pub fn appendable(&self) -> B {
+        self.appendable
+    }
+
+    /// Converts this into a wrapper that doesn't allow appending to the value.
+    #[inline]
+    pub fn freeze(self) -> AppendOnlyIfTrue<T, False> {
+        AppendOnlyIfTrue {
+            value: self.value,
+            appendable: False,
+        }
+    }
+}
+
+impl<T> AppendOnlyIfTrue<T, True> {
+    /// Appends `elem` to the wrapped collection.
+    #[inline]
+    pub fn push<A>(&mut self, elem: A)
+    where
+        T: Extend<A>,
+    {
+        self.value.extend(Some(elem));
+    }
+
+    /// Appends all the items from `iter` to the wrapped collection.
+    #[inline]
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator,
+        T: Extend<I::Item>,
+    {
+        self.value.extend(iter);
+    }
+}
+
+impl<T, B> ops::Deref for AppendOnlyIfTrue<T, B> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// `And`s any amount of [Boolean](./type_level_bool/trait.Boolean.html)s,
+/// evaluating to [`True`] if there are none.
+///
+/// This is equivalent to nesting [`And`]s, ie: `All!(A, B, C)` is `And<A, And<B, C>>`.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::All;
+/// use core_extensions::type_level_bool::{Boolean, False, True};
+///
+/// assert_eq!(<All!(True, True, True)>::VALUE, true);
+/// assert_eq!(<All!(True, True, False)>::VALUE, false);
+/// assert_eq!(<All!(False, True, True, True)>::VALUE, false);
+/// assert_eq!(<All!(False)>::VALUE, false);
+/// assert_eq!(<All!()>::VALUE, true);
+///
+/// ```
+///
+/// [`True`]: ./type_level_bool/struct.True.html
+/// [`And`]: ./type_level_bool/type.And.html
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "type_level_bool")))]
+#[macro_export]
+macro_rules! All {
+    () => ($crate::type_level_bool::True);
+    ($first:ty $(, $rest:ty)* $(,)*) => (
+        $crate::type_level_bool::And<$first, $crate::All!($($rest),*)>
+    );
+}
+
+/// `Or`s any amount of [Boolean](./type_level_bool/trait.Boolean.html)s,
+/// evaluating to [`False`] if there are none.
+///
+/// This is equivalent to nesting [`Or`]s, ie: `Any!(A, B, C)` is `Or<A, Or<B, C>>`.
+///
+/// # Example
+///
+/// ```rust
+/// use core_extensions::Any;
+/// use core_extensions::type_level_bool::{Boolean, False, True};
+///
+/// assert_eq!(<Any!(False, False, True)>::VALUE, true);
+/// assert_eq!(<Any!(False, False, False)>::VALUE, false);
+/// assert_eq!(<Any!(True, False, False, False)>::VALUE, true);
+/// assert_eq!(<Any!(True)>::VALUE, true);
+/// assert_eq!(<Any!()>::VALUE, false);
+///
+/// ```
+///
+/// [`False`]: ./type_level_bool/struct.False.html
+/// [`Or`]: ./type_level_bool/type.Or.html
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "type_level_bool")))]
+#[macro_export]
+macro_rules! Any {
+    () => ($crate::type_level_bool::False);
+    ($first:ty $(, $rest:ty)* $(,)*) => (
+        $crate::type_level_bool::Or<$first, $crate::Any!($($rest),*)>
+    );
+}
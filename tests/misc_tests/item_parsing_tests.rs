@@ -156,14 +156,51 @@ fn parse_generics_test() {
 
 
 
-
-
-
-
-
-
-
-
-
-
-
+#[test]
+fn parse_const_item_tests() {
+    assert_is!{
+        parse_const_item
+        {aa bb}
+        (
+            #[doc = "hello"]
+            #[allow(dead_code)]
+            pub(crate) const FOO: Option<(&'static [u8], fn(u32) -> u64)> = 
+                Some((&[3, 5], |x| { let y = x as u64; y * 2 }));
+        )
+        (
+            aa bb
+            (#[doc = "hello"] #[allow(dead_code)])
+            (pub(crate))
+            (const)
+            (FOO)
+            (Option<(&'static [u8], fn(u32) -> u64)>)
+            (Some((&[3, 5], |x| { let y = x as u64; y * 2 })))
+        )
+    }
+    assert_is!{
+        parse_const_item
+        {aa bb}
+        (const _: () = ())
+        (
+            aa bb
+            ()
+            ()
+            (const)
+            (_)
+            (())
+            (())
+        )
+    }
+    assert_is!{
+        parse_const_item
+        {aa bb}
+        (static BAR: [u8; 2 + 3] = [0; 5];)
+        (aa bb () () (static) (BAR) ([u8; 2 + 3]) ([0; 5]))
+    }
+    assert_is!{
+        parse_const_item
+        {aa bb}
+        (pub static mut BAZ: Vec<u8> = Vec::new();)
+        (aa bb () (pub) (static mut) (BAZ) (Vec<u8>) (Vec::new()))
+    }
+}
@@ -173,6 +173,17 @@ fn split_starter_test() {
     assert_tm!{"(1+1) (2+2) (3+3)", split_starter("hello"): (1 + 1 "hello" 2 + 2 "hello" 3 + 3)}
 }
 
+#[test]
+fn fold_test() {
+    assert_tm!{"()(b)", fold(): (b)}
+    assert_tm!{"(a)(b)", fold(a): (b)}
+    assert_tm!{"(declare_const!{hello (a)(b)})(c)", fold(a): (b c)}
+    assert_tm!{
+        "(declare_const!{hello (declare_const!{hello (a b)((c))})(d)})(e)",
+        fold(a b): ((c) d e)
+    }
+}
+
 macro_rules! test_zip_fn {
     (
         $zip_fn:ident ($(( $($e:expr),* ))*)
@@ -51,6 +51,62 @@ fn lifetimes() {
             ($crate::__::PD<(&'a(),&'c(),&'d(),&'g(),$crate::__::PD<T>,)>)
         )
     }
+
+    // duplicate lifetime bounds are removed, preserving the order of the rest
+    #[cfg(feature = "rust_1_46")]
+    assert_is!{
+        parse_generics
+        {aa bb}
+        ('a: 'b + 'b, 'c: 'd + 'e + 'd + 'f + 'e, 'g:)
+        (
+            aa bb
+            ('a: 'b + , 'c: 'd + 'e + 'f + , 'g:,)
+            ('a: 'b + , 'c: 'd + 'e + 'f + , 'g:,)
+            ('a, 'c, 'g,)
+            ($crate::__::PD<(&'a(),&'c(),&'g(),)>)
+        )
+    }
+
+    // duplicate lifetime bounds are kept without the "rust_1_46" feature
+    #[cfg(not(feature = "rust_1_46"))]
+    assert_is!{
+        parse_generics
+        {aa bb}
+        ('a: 'b + 'b, 'c: 'd + 'e + 'd, 'g:)
+        (
+            aa bb
+            ('a: 'b + 'b + , 'c: 'd + 'e + 'd + , 'g:,)
+            ('a: 'b + 'b + , 'c: 'd + 'e + 'd + , 'g:,)
+            ('a, 'c, 'g,)
+            ($crate::__::PD<(&'a(),&'c(),&'g(),)>)
+        )
+    }
+}
+
+#[test]
+fn lifetime_bounds_outside_items() {
+    macro_rules! phantom_type {
+        ($struct_params:tt $impl_params:tt $impl_args:tt ($($phantom:tt)*)) => {
+            $($phantom)*
+        };
+    }
+
+    fn with_bounds<'a, 'b: 'a, T>(_: &'a (), _: &'b ()) {
+        let _: parse_generics!{phantom_type!{} ('a: 'b, 'b: 'a + 'a, T)} =
+            core::marker::PhantomData::<(&'a (), &'b (), core::marker::PhantomData<T>)>;
+    }
+    with_bounds::<u8>(&(), &());
+
+    let string = parse_generics!{stringify!{} ('a: 'b, 'b, T)};
+    assert_eq!(
+        remove_whitespace(string),
+        remove_whitespace("
+            ('a: 'b + , 'b, T,)
+            ('a: 'b + , 'b, T,)
+            ('a, 'b, T,)
+            ($crate::__::PD<(&'a(),&'b(),$crate::__::PD<T>,)>)
+        "),
+    );
 }
 
 #[test]
@@ -0,0 +1,70 @@
+use krate::measure_time::timed;
+
+use std::num::ParseIntError;
+
+
+#[timed]
+fn no_return() {}
+
+#[timed]
+fn generic<T>(x: T, y: T) -> (T, T)
+where
+    T: Clone,
+{
+    (y, x)
+}
+
+#[timed]
+fn early_return(n: u32) -> &'static str {
+    if n == 0 {
+        return "zero";
+    }
+    "nonzero"
+}
+
+#[timed]
+fn question_mark(s: &str) -> Result<u32, ParseIntError> {
+    let n = s.parse::<u32>()?;
+    Ok(n * 2)
+}
+
+#[timed(crate = krate)]
+fn renamed_crate(x: u32) -> u32 {
+    x + 1
+}
+
+#[timed(crate = ::krate)]
+fn absolute_renamed_crate(x: u32) -> u32 {
+    x * 2
+}
+
+struct Counter(u32);
+
+impl Counter {
+    #[timed]
+    pub fn increment(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+#[test]
+fn timed_returns_values() {
+    let () = no_return();
+
+    assert_eq!(generic(3, 5), (5, 3));
+    assert_eq!(generic("foo", "bar"), ("bar", "foo"));
+
+    assert_eq!(early_return(0), "zero");
+    assert_eq!(early_return(1), "nonzero");
+
+    assert_eq!(question_mark("21"), Ok(42));
+    assert!(question_mark("foo").is_err());
+
+    assert_eq!(renamed_crate(3), 4);
+    assert_eq!(absolute_renamed_crate(3), 6);
+
+    let mut counter = Counter(0);
+    assert_eq!(counter.increment(), 1);
+    assert_eq!(counter.increment(), 2);
+}
@@ -175,4 +175,36 @@ fn vec_test() {
 
         assert_eq!(into, vec![3, 5, 8]);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pin_test() {
+    use core::{
+        marker::PhantomPinned,
+        pin::Pin,
+    };
+
+    use alloc::boxed::Box;
+
+    #[derive(Debug, PartialEq)]
+    struct Unmovable(u32, PhantomPinned);
+
+    let mut inner = Box::pin(Unmovable(3, PhantomPinned));
+    let inner_addr = &*inner as *const Unmovable as usize;
+
+    {
+        let foo = unsafe{ Trans::from_inner_pin_ref(inner.as_ref()) };
+        assert_tyoe::<_, Pin<&Trans<Unmovable>>>(&foo);
+        assert_eq!(&*foo as *const Trans<Unmovable> as usize, inner_addr);
+        assert_eq!((foo.0).0, 3);
+    }
+    {
+        let mut foo = unsafe{ Trans::from_inner_pin_mut(inner.as_mut()) };
+        assert_tyoe::<_, Pin<&mut Trans<Unmovable>>>(&foo);
+        assert_eq!(&*foo as *const Trans<Unmovable> as usize, inner_addr);
+
+        unsafe{ (foo.as_mut().get_unchecked_mut().0).0 = 5; }
+    }
+    assert_eq!(inner.0, 5);
+}
@@ -33,6 +33,9 @@ mod misc_tests {
     
     #[cfg(feature = "option_result")]
     mod result_option_extension_tests;
+
+    #[cfg(feature = "timed")]
+    mod timed_tests;
     
 
 }